@@ -0,0 +1,400 @@
+//! Regenerates the region/partition tables (`src/region_data.rs`) and the
+//! service availability table (`src/availability/data.rs`) from botocore's
+//! `endpoints.json`:
+//!
+//! ```sh
+//! cargo run --example codegen -- path/to/botocore/data/endpoints.json
+//! cargo fmt
+//! ```
+//!
+//! New regions also need an entry in the hand-maintained geography table in
+//! `src/geography.rs`; the build fails until they have one.
+
+use serde_json::Value;
+use std::{env, fmt::Write, fs, process};
+
+const REGION_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/region_data.rs");
+const AVAILABILITY_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/availability/data.rs");
+
+// Regions this crate has shipped that `endpoints.json` doesn't list (yet);
+// kept so a refresh never removes a variant. (name, partition, description)
+const RETAINED_REGIONS: &[(&str, &str, &str)] = &[("eu-north-2", "aws", "Europe")];
+
+// Endpoint prefixes whose ARNs use a different service namespace, so the
+// availability table is keyed the way ARNs are. API Gateway serves both
+// `apigateway` (management) and `execute-api` (invoke) ARNs.
+const ARN_NAMESPACES: &[(&str, &[&str])] = &[
+    ("api.detective", &["detective"]),
+    ("api.ecr", &["ecr"]),
+    ("api.ecr-public", &["ecr-public"]),
+    ("api.iotdeviceadvisor", &["iotdeviceadvisor"]),
+    ("api.iotwireless", &["iotwireless"]),
+    ("api.mediatailor", &["mediatailor"]),
+    ("api.pricing", &["pricing"]),
+    ("api.sagemaker", &["sagemaker"]),
+    ("apigateway", &["apigateway", "execute-api"]),
+    ("email", &["ses"]),
+    ("monitoring", &["cloudwatch"]),
+];
+
+struct Partition {
+    name: String,
+    description: String,
+    dns_suffix: String,
+}
+
+struct Region {
+    name: String,
+    partition: usize,
+    description: String,
+}
+
+struct Service {
+    name: String,
+    regionalized: bool,
+    regions: u64,
+}
+
+fn variant(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn main() {
+    let Some(path) = env::args().nth(1) else {
+        eprintln!("usage: codegen <endpoints.json>");
+        process::exit(2);
+    };
+    let source = fs::read_to_string(&path).unwrap_or_else(|e| fail(format!("{path}: {e}")));
+    let data: Value = serde_json::from_str(&source)
+        .unwrap_or_else(|e| fail(format!("{path} is not valid JSON: {e}")));
+    let header = format!(
+        "// @generated by `cargo run --example codegen` from botocore endpoints.json \
+         (format version {}). Do not edit.\n",
+        data["version"]
+    );
+
+    let mut partitions = Vec::new();
+    let mut regions = Vec::new();
+    for partition in data["partitions"].as_array().into_iter().flatten() {
+        let index = partitions.len();
+        partitions.push(Partition {
+            name: partition["partition"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            description: partition["partitionName"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            dns_suffix: partition["dnsSuffix"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        });
+        for (name, region) in partition["regions"].as_object().into_iter().flatten() {
+            regions.push(Region {
+                name: name.clone(),
+                partition: index,
+                description: region["description"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+    }
+    for (name, partition, description) in RETAINED_REGIONS {
+        if !regions.iter().any(|r| r.name == *name) {
+            let partition = partitions
+                .iter()
+                .position(|p| p.name == *partition)
+                .unwrap_or_else(|| fail(format!("unknown partition {partition}")));
+            regions.push(Region {
+                name: name.to_string(),
+                partition,
+                description: description.to_string(),
+            });
+        }
+    }
+    if partitions.iter().all(|p| p.name != "aws") || regions.iter().all(|r| r.name != "us-east-1") {
+        fail(format!("{path} has no aws partition or us-east-1 region"));
+    }
+    if regions.len() > 64 {
+        fail("more than 64 regions; widen the availability bitmask".to_string());
+    }
+
+    // Services are merged across partitions (and endpoint prefixes sharing an
+    // ARN namespace); a global service is available in every region of each
+    // partition it appears in.
+    let mut services: Vec<Service> = Vec::new();
+    for (index, partition) in data["partitions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let partition_mask = regions
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.partition == index)
+            .fold(0u64, |mask, (i, _)| mask | 1 << i);
+        for (name, service) in partition["services"].as_object().into_iter().flatten() {
+            let regionalized = service["isRegionalized"].as_bool().unwrap_or(true);
+            let mask = if regionalized {
+                service["endpoints"]
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, endpoint)| endpoint["deprecated"] != true)
+                    .filter_map(|(region, _)| regions.iter().position(|r| r.name == *region))
+                    .fold(0u64, |mask, i| mask | 1 << i)
+            } else {
+                partition_mask
+            };
+            let unaliased = [name.as_str()];
+            let namespaces = ARN_NAMESPACES
+                .iter()
+                .find(|(prefix, _)| prefix == name)
+                .map_or(&unaliased[..], |(_, namespaces)| namespaces);
+            for namespace in namespaces {
+                match services.iter_mut().find(|s| s.name == *namespace) {
+                    Some(existing) => existing.regions |= mask,
+                    None => services.push(Service {
+                        name: namespace.to_string(),
+                        regionalized,
+                        regions: mask,
+                    }),
+                }
+            }
+        }
+    }
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let region_data = render_regions(&header, &partitions, &regions);
+    let availability_data = render_availability(&header, &services);
+    for (output, contents) in [
+        (REGION_OUTPUT, region_data),
+        (AVAILABILITY_OUTPUT, availability_data),
+    ] {
+        fs::write(output, contents).unwrap_or_else(|e| fail(format!("{output}: {e}")));
+    }
+}
+
+fn render_regions(header: &str, partitions: &[Partition], regions: &[Region]) -> String {
+    let mut out = String::new();
+    let w = &mut out;
+    writeln!(w, "{header}").unwrap();
+    writeln!(w, "use crate::RegionError;").unwrap();
+    writeln!(w, "use std::str::FromStr;").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(
+        w,
+        "#[derive(Copy, Clone, Default, Hash, PartialEq, Eq, Debug, strum::VariantArray)]"
+    )
+    .unwrap();
+    writeln!(w, "pub enum Partition {{").unwrap();
+    for partition in partitions {
+        writeln!(w, "    /// {}", partition.description).unwrap();
+        if partition.name == "aws" {
+            writeln!(w, "    #[default]").unwrap();
+        }
+        writeln!(w, "    {},", variant(&partition.name)).unwrap();
+    }
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl Partition {{").unwrap();
+    writeln!(w, "    pub fn dns_suffix(&self) -> &'static str {{").unwrap();
+    writeln!(w, "        match self {{").unwrap();
+    for partition in partitions {
+        writeln!(
+            w,
+            "            Partition::{} => {:?},",
+            variant(&partition.name),
+            partition.dns_suffix
+        )
+        .unwrap();
+    }
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl AsRef<str> for Partition {{").unwrap();
+    writeln!(w, "    fn as_ref(&self) -> &str {{").unwrap();
+    writeln!(w, "        match self {{").unwrap();
+    for partition in partitions {
+        writeln!(
+            w,
+            "            Partition::{} => {:?},",
+            variant(&partition.name),
+            partition.name
+        )
+        .unwrap();
+    }
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl FromStr for Partition {{").unwrap();
+    writeln!(w, "    type Err = RegionError;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{").unwrap();
+    writeln!(w, "        match s {{").unwrap();
+    for partition in partitions {
+        writeln!(
+            w,
+            "            {:?} => Ok(Partition::{}),",
+            partition.name,
+            variant(&partition.name)
+        )
+        .unwrap();
+    }
+    writeln!(
+        w,
+        "            _ => Err(RegionError::UnknownPartition(s.to_string())),"
+    )
+    .unwrap();
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(
+        w,
+        "#[derive(Copy, Clone, Default, Hash, PartialEq, Eq, Debug, strum::VariantArray)]"
+    )
+    .unwrap();
+    writeln!(w, "pub enum Region {{").unwrap();
+    for region in regions {
+        writeln!(w, "    /// {}", region.description).unwrap();
+        if region.name == "us-east-1" {
+            writeln!(w, "    #[default]").unwrap();
+        }
+        writeln!(w, "    {},", variant(&region.name)).unwrap();
+    }
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl Region {{").unwrap();
+    writeln!(w, "    pub fn partition(&self) -> Partition {{").unwrap();
+    writeln!(w, "        use Region::*;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "        match self {{").unwrap();
+    for (index, partition) in partitions.iter().enumerate() {
+        let members: Vec<_> = regions
+            .iter()
+            .filter(|r| r.partition == index)
+            .map(|r| variant(&r.name))
+            .collect();
+        if !members.is_empty() {
+            writeln!(
+                w,
+                "            {} => Partition::{},",
+                members.join(" | "),
+                variant(&partition.name)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "    pub fn description(&self) -> &'static str {{").unwrap();
+    writeln!(w, "        use Region::*;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "        match self {{").unwrap();
+    for region in regions {
+        writeln!(
+            w,
+            "            {} => {:?},",
+            variant(&region.name),
+            region.description
+        )
+        .unwrap();
+    }
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl AsRef<str> for Region {{").unwrap();
+    writeln!(w, "    fn as_ref(&self) -> &str {{").unwrap();
+    writeln!(w, "        use Region::*;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "        match self {{").unwrap();
+    for region in regions {
+        writeln!(
+            w,
+            "            {} => {:?},",
+            variant(&region.name),
+            region.name
+        )
+        .unwrap();
+    }
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    writeln!(w).unwrap();
+
+    writeln!(w, "impl FromStr for Region {{").unwrap();
+    writeln!(w, "    type Err = RegionError;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{").unwrap();
+    writeln!(w, "        use Region::*;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "        match s {{").unwrap();
+    for region in regions {
+        writeln!(
+            w,
+            "            {:?} => Ok({}),",
+            region.name,
+            variant(&region.name)
+        )
+        .unwrap();
+    }
+    writeln!(
+        w,
+        "            _ => Err(RegionError::DoesNotExist(s.to_string())),"
+    )
+    .unwrap();
+    writeln!(w, "        }}").unwrap();
+    writeln!(w, "    }}").unwrap();
+    writeln!(w, "}}").unwrap();
+    out
+}
+
+fn render_availability(header: &str, services: &[Service]) -> String {
+    let mut out = String::new();
+    let w = &mut out;
+    writeln!(w, "{header}").unwrap();
+    writeln!(w, "use super::Service;").unwrap();
+    writeln!(w).unwrap();
+    writeln!(w, "// Bit `n` of each mask is `Region::VARIANTS[n]`.").unwrap();
+    writeln!(w, "#[rustfmt::skip]").unwrap();
+    writeln!(w, "pub(super) static SERVICES: &[Service] = &[").unwrap();
+    for service in services {
+        writeln!(
+            w,
+            "    Service::new({:?}, {}, {:#018x}),",
+            service.name, service.regionalized, service.regions
+        )
+        .unwrap();
+    }
+    writeln!(w, "];").unwrap();
+    out
+}
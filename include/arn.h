@@ -0,0 +1,41 @@
+/* C interface to the `arn` crate, built with `--features ffi`. */
+
+#ifndef ARN_H
+#define ARN_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct Arn Arn;
+
+/* Returns NULL on failure; see arn_last_error(). Release with arn_free(). */
+Arn *arn_parse(const char *input);
+void arn_free(Arn *arn);
+
+/* Message for the last failure on this thread, or NULL. */
+const char *arn_last_error(void);
+
+/*
+ * Copy a component (or the whole ARN) into buf, writing at most len bytes
+ * including the NUL terminator. Returns the full length; a result >= len
+ * means the output was truncated. A NULL arn returns 0 and sets
+ * arn_last_error() to "null ARN".
+ */
+size_t arn_to_string(const Arn *arn, char *buf, size_t len);
+size_t arn_partition(const Arn *arn, char *buf, size_t len);
+size_t arn_service(const Arn *arn, char *buf, size_t len);
+size_t arn_region(const Arn *arn, char *buf, size_t len);
+size_t arn_account(const Arn *arn, char *buf, size_t len);
+size_t arn_resource(const Arn *arn, char *buf, size_t len);
+
+/* 1 if arn matches the IAM-style pattern, 0 if not, -1 on error. */
+int arn_matches(const char *pattern, const Arn *arn);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ARN_H */
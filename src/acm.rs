@@ -0,0 +1,152 @@
+//! ACM certificate and ACM Private CA certificate authority ARNs.
+
+use crate::{
+    Arn, ArnField, ArnParseError, Component, Region,
+    typed::{is_uuid, resource, resource_of},
+    validate::ValidationError,
+};
+
+fn check_id(id: &str, what: &str) -> Result<(), ValidationError> {
+    if !is_uuid(id) {
+        return Err(ValidationError::invalid(
+            ArnField::Resource,
+            format!("'{id}' is not a {what} ID"),
+        ));
+    }
+    Ok(())
+}
+
+fn new_arn(
+    service: &str,
+    region: Region,
+    account: &str,
+    resource: &str,
+) -> Result<Arn, ArnParseError> {
+    let mut arn = Arn {
+        partition: region.partition(),
+        region: Component::Value(region),
+        ..Arn::default()
+    };
+    arn.set_service(service)?;
+    arn.set_account(account)?;
+    arn.set_resource_id(resource)?;
+    Ok(arn)
+}
+
+/// `arn:aws:acm:{region}:{account}:certificate/{id}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct CertificateArn(Arn);
+
+typed_arn!(CertificateArn);
+
+impl CertificateArn {
+    pub fn new(region: Region, account: &str, id: &str) -> Result<Self, ValidationError> {
+        check_id(id, "certificate")?;
+        Ok(CertificateArn(new_arn(
+            "acm",
+            region,
+            account,
+            &format!("certificate/{id}"),
+        )?))
+    }
+
+    /// The certificate ID, a UUID.
+    pub fn certificate_id(&self) -> &str {
+        &resource_of(&self.0)["certificate/".len()..]
+    }
+}
+
+impl TryFrom<Arn> for CertificateArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        check_id(resource(&arn, "acm", "certificate/")?, "certificate")?;
+        Ok(CertificateArn(arn))
+    }
+}
+
+/// `arn:aws:acm-pca:{region}:{account}:certificate-authority/{id}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct CertificateAuthorityArn(Arn);
+
+typed_arn!(CertificateAuthorityArn);
+
+impl CertificateAuthorityArn {
+    pub fn new(region: Region, account: &str, id: &str) -> Result<Self, ValidationError> {
+        check_id(id, "certificate authority")?;
+        Ok(CertificateAuthorityArn(new_arn(
+            "acm-pca",
+            region,
+            account,
+            &format!("certificate-authority/{id}"),
+        )?))
+    }
+
+    /// The certificate authority ID, a UUID.
+    pub fn authority_id(&self) -> &str {
+        &resource_of(&self.0)["certificate-authority/".len()..]
+    }
+}
+
+impl TryFrom<Arn> for CertificateAuthorityArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        check_id(
+            resource(&arn, "acm-pca", "certificate-authority/")?,
+            "certificate authority",
+        )?;
+        Ok(CertificateAuthorityArn(arn))
+    }
+}
+
+impl Arn {
+    /// Whether this is an ACM Private CA certificate authority rather than,
+    /// say, an ACM certificate.
+    pub fn is_certificate_authority(&self) -> bool {
+        CertificateAuthorityArn::try_from(*self).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "12345678-90ab-cdef-1234-567890abcdef";
+
+    #[test]
+    fn test_certificate() {
+        let arn = format!("arn:aws:acm:us-east-1:123456789012:certificate/{ID}");
+        let cert: CertificateArn = arn.parse().unwrap();
+        assert_eq!(cert.certificate_id(), ID);
+        assert_eq!(
+            CertificateArn::new(Region::UsEast1, "123456789012", ID).unwrap(),
+            cert
+        );
+        assert!(!cert.is_certificate_authority());
+
+        assert!(CertificateArn::new(Region::UsEast1, "123456789012", "not-a-uuid").is_err());
+        assert!(
+            "arn:aws:acm:us-east-1:123456789012:certificate/1234"
+                .parse::<CertificateArn>()
+                .is_err()
+        );
+        assert!(
+            format!("arn:aws:acm-pca:us-east-1:123456789012:certificate/{ID}")
+                .parse::<CertificateArn>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_certificate_authority() {
+        let ca = CertificateAuthorityArn::new(Region::CnNorth1, "123456789012", ID).unwrap();
+        assert_eq!(
+            ca.to_string(),
+            format!("arn:aws-cn:acm-pca:cn-north-1:123456789012:certificate-authority/{ID}")
+        );
+        assert_eq!(ca.authority_id(), ID);
+        assert!(ca.is_certificate_authority());
+        assert!(!Arn::ANY.is_certificate_authority());
+    }
+}
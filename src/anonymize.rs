@@ -0,0 +1,227 @@
+use crate::{Arn, Component};
+use arrayvec::ArrayString;
+use std::fmt::Write;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (i, word) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes(word.try_into().unwrap());
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+    let len: usize = parts.iter().map(|p| p.len()).sum();
+    let mut message = Vec::with_capacity(len + 72);
+    for part in parts {
+        message.extend_from_slice(part);
+    }
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(len as u64 * 8).to_be_bytes());
+    for block in message.chunks_exact(64) {
+        compress(&mut state, block);
+    }
+
+    let mut digest = [0u8; 32];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > 64 {
+        block[..32].copy_from_slice(&sha256(&[key]));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let ipad = block.map(|b| b ^ 0x36);
+    let opad = block.map(|b| b ^ 0x5c);
+
+    let mut inner_parts = vec![&ipad[..]];
+    inner_parts.extend_from_slice(parts);
+    let inner = sha256(&inner_parts);
+    sha256(&[&opad, &inner])
+}
+
+impl Arn {
+    /// Pseudonymizes the account and resource with HMAC-SHA256 under `key`,
+    /// keeping the service, region, and resource type (e.g. the `role/` in
+    /// `role/my-role`) intact. The same key always yields the same tokens, so
+    /// anonymized datasets can still be joined.
+    ///
+    /// Accounts become another 12-digit ID, except the AWS-managed `aws`
+    /// account, which isn't sensitive; resource names become 32 hex
+    /// characters.
+    pub fn anonymize(&self, key: &[u8]) -> Arn {
+        let account = match self.account {
+            Component::Value(account) if account.as_str() != "aws" => {
+                let mac = hmac_sha256(key, &[b"account\0", account.as_bytes()]);
+                let n = u64::from_be_bytes(mac[..8].try_into().unwrap()) % 1_000_000_000_000;
+                let mut token = ArrayString::new();
+                write!(token, "{n:012}").unwrap();
+                Component::Value(token)
+            }
+            other => other,
+        };
+
+        let resource_id = match self.resource_id {
+            Component::Value(resource) => {
+                let (kind, name) = match resource.find(['/', ':']) {
+                    Some(i) => resource.split_at(i + 1),
+                    None => ("", resource.as_str()),
+                };
+                let mac = hmac_sha256(key, &[b"resource\0", resource.as_bytes()]);
+                let mut token = ArrayString::<64>::new();
+                token.push_str(kind);
+                if !name.is_empty() {
+                    for b in &mac[..16] {
+                        if write!(token, "{b:02x}").is_err() {
+                            break;
+                        }
+                    }
+                }
+                Component::Value(token)
+            }
+            other => other,
+        };
+
+        Arn {
+            account,
+            resource_id,
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            hex(&sha256(&[b""])),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256(&[b"a", b"bc"])),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(&sha256(&[
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            ])),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231 test cases 2 and 6.
+        assert_eq!(
+            hex(&hmac_sha256(b"Jefe", &[b"what do ya want for nothing?"])),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            hex(&hmac_sha256(
+                &[0xaa; 131],
+                &[b"Test Using Larger Than Block-Size Key - Hash Key First"]
+            )),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let arn: Arn = "arn:aws:iam::123456789012:role/my-role".parse().unwrap();
+        let anonymized = arn.anonymize(b"secret");
+        assert_eq!(anonymized.service, arn.service);
+        assert_eq!(anonymized.region, arn.region);
+        assert_ne!(anonymized.account, arn.account);
+        assert_eq!(anonymized, arn.anonymize(b"secret"));
+        assert_ne!(anonymized, arn.anonymize(b"other"));
+
+        let s = anonymized.to_string();
+        let resource = s.strip_prefix("arn:aws:iam::").unwrap();
+        let (account, resource) = resource.split_once(':').unwrap();
+        assert_eq!(account.len(), 12);
+        assert!(account.chars().all(|c| c.is_ascii_digit()));
+        let token = resource.strip_prefix("role/").unwrap();
+        assert_eq!(token.len(), 32);
+        assert_eq!(s.parse::<Arn>().unwrap(), anonymized);
+    }
+
+    #[test]
+    fn test_anonymize_keeps_aws_account() {
+        let policy: Arn = "arn:aws:iam::aws:policy/ReadOnlyAccess".parse().unwrap();
+        let anonymized = policy.anonymize(b"key");
+        assert_eq!(anonymized.account, policy.account);
+        assert_ne!(anonymized.resource_id, policy.resource_id);
+        assert_eq!(anonymized, policy.anonymize(b"key"));
+    }
+
+    #[test]
+    fn test_anonymize_keeps_wildcards() {
+        assert_eq!(Arn::ANY.anonymize(b"secret"), Arn::ANY);
+        let arn: Arn = "arn:aws:s3:::bucket".parse().unwrap();
+        let anonymized = arn.anonymize(b"secret");
+        assert_eq!(anonymized.account, Component::None);
+        assert!(matches!(anonymized.resource_id, Component::Value(id) if id.len() == 32));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{Arn, Component, Region};
+use strum::VariantArray;
+
+mod data;
+
+/// Where an AWS service has endpoints, per botocore's `endpoints.json`.
+///
+/// Services are keyed by ARN service namespace (`ecr`, `cloudwatch`,
+/// `execute-api`), not by endpoint prefix (`api.ecr`, `monitoring`).
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Service {
+    name: &'static str,
+    regionalized: bool,
+    regions: u64,
+}
+
+impl Service {
+    const fn new(name: &'static str, regionalized: bool, regions: u64) -> Self {
+        Self {
+            name,
+            regionalized,
+            regions,
+        }
+    }
+
+    pub fn get(name: &str) -> Option<&'static Service> {
+        data::SERVICES
+            .binary_search_by(|service| service.name.cmp(name))
+            .ok()
+            .map(|i| &data::SERVICES[i])
+    }
+
+    pub fn all() -> &'static [Service] {
+        data::SERVICES
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// `false` for global services such as IAM and Route 53, which are
+    /// reachable from every region.
+    pub fn is_regionalized(&self) -> bool {
+        self.regionalized
+    }
+
+    /// Whether every ARN for this service has a region; `false` for global
+    /// services and for S3, whose bucket ARNs have none.
+    pub fn requires_region(&self) -> bool {
+        crate::global::requires_region(self.name)
+    }
+
+    /// Whether every ARN for this service has an account; `false` for S3 and
+    /// Route 53.
+    pub fn requires_account(&self) -> bool {
+        crate::global::requires_account(self.name)
+    }
+
+    pub fn is_available_in(&self, region: Region) -> bool {
+        self.regions & 1 << region as u8 != 0
+    }
+
+    pub fn available_regions(&self) -> impl Iterator<Item = Region> + '_ {
+        Region::VARIANTS
+            .iter()
+            .copied()
+            .filter(|region| self.is_available_in(*region))
+    }
+}
+
+impl Region {
+    pub fn supports_service(&self, service: &str) -> bool {
+        Service::get(service).is_some_and(|service| service.is_available_in(*self))
+    }
+
+    pub fn services(&self) -> impl Iterator<Item = &'static Service> + '_ {
+        Service::all()
+            .iter()
+            .filter(|service| service.is_available_in(*self))
+    }
+}
+
+impl Arn {
+    /// `false` if the ARN names a known service in a region where that service
+    /// has no endpoint, e.g. Bedrock in a region it hasn't launched in. ARNs
+    /// with unknown services or without a concrete region are never rejected.
+    pub fn is_service_available(&self) -> bool {
+        match (&self.service, &self.region) {
+            (Component::Value(service), Component::Value(region)) => {
+                Service::get(service).is_none_or(|service| service.is_available_in(*region))
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Partition;
+
+    #[test]
+    fn test_services_sorted() {
+        assert!(Service::all().windows(2).all(|w| w[0].name < w[1].name));
+    }
+
+    #[test]
+    fn test_service_get() {
+        let eks = Service::get("eks").unwrap();
+        assert_eq!(eks.name(), "eks");
+        assert!(eks.is_regionalized());
+        assert!(eks.is_available_in(Region::UsEast1));
+        assert!(eks.available_regions().any(|r| r == Region::EuWest1));
+        assert!(Service::get("not-a-service").is_none());
+    }
+
+    #[test]
+    fn test_global_service() {
+        let iam = Service::get("iam").unwrap();
+        assert!(!iam.is_regionalized());
+        assert!(
+            Region::VARIANTS
+                .iter()
+                .filter(|region| region.partition() == Partition::Aws)
+                .all(|region| iam.is_available_in(*region))
+        );
+        assert!(!iam.requires_region());
+        assert!(iam.requires_account());
+        assert!(Service::get("ec2").unwrap().requires_region());
+        assert!(!Service::get("s3").unwrap().requires_account());
+    }
+
+    #[test]
+    fn test_region_supports_service() {
+        assert!(Region::UsEast1.supports_service("s3"));
+        for service in [
+            "ecr",
+            "sagemaker",
+            "cloudwatch",
+            "execute-api",
+            "apigateway",
+            "ses",
+        ] {
+            assert!(Region::UsEast1.supports_service(service), "{service}");
+        }
+        assert!(Service::get("api.ecr").is_none());
+        assert!(Service::get("monitoring").is_none());
+        assert!(!Region::UsEast1.supports_service("not-a-service"));
+        assert!(Region::UsEast1.services().any(|s| s.name() == "lambda"));
+    }
+
+    #[test]
+    fn test_arn_is_service_available() {
+        let unavailable = Service::all()
+            .iter()
+            .find_map(|service| {
+                Region::VARIANTS
+                    .iter()
+                    .find(|region| !service.is_available_in(**region))
+                    .map(|region| (service.name(), region))
+            })
+            .unwrap();
+        let arn: Arn = format!(
+            "arn:aws:{}:{}:123456789012:x",
+            unavailable.0,
+            unavailable.1.as_ref()
+        )
+        .parse()
+        .unwrap();
+        assert!(!arn.is_service_available());
+
+        let arn: Arn = "arn:aws:lambda:us-east-1:123456789012:function:f"
+            .parse()
+            .unwrap();
+        assert!(arn.is_service_available());
+
+        let arn: Arn = "arn:aws:ecr:us-east-1:123456789012:repository/app"
+            .parse()
+            .unwrap();
+        assert!(arn.is_service_available());
+        assert!(Service::get("ecr").is_some());
+
+        let arn: Arn = "arn:aws:internal-thing:us-east-1:123456789012:x"
+            .parse()
+            .unwrap();
+        assert!(arn.is_service_available());
+    }
+}
@@ -0,0 +1,318 @@
+// @generated by `cargo run --example codegen` from botocore endpoints.json (format version 3). Do not edit.
+
+use super::Service;
+
+// Bit `n` of each mask is `Region::VARIANTS[n]`.
+#[rustfmt::skip]
+pub(super) static SERVICES: &[Service] = &[
+    Service::new("access-analyzer", true, 0x00003c3fffffffff),
+    Service::new("account", false, 0x0000400fffffffff),
+    Service::new("acm", true, 0x00003fffffffffff),
+    Service::new("acm-pca", true, 0x00003cffffffffff),
+    Service::new("agreement-marketplace", true, 0x0000314040000000),
+    Service::new("airflow", true, 0x0000000fefffdffb),
+    Service::new("amplify", true, 0x00000003e9da837a),
+    Service::new("amplifybackend", true, 0x00000003e9da8378),
+    Service::new("amplifyuibuilder", true, 0x00000003e9da8378),
+    Service::new("aoss", true, 0x00000033e1fe83fa),
+    Service::new("api.tunneling.iot", true, 0x0000003feffa935a),
+    Service::new("apigateway", true, 0x000025ffffffffff),
+    Service::new("app-integrations", true, 0x0000000240828319),
+    Service::new("appconfig", true, 0x00003fffffffffff),
+    Service::new("appconfigdata", true, 0x00003fffffffffff),
+    Service::new("appflow", true, 0x00000003e1c28359),
+    Service::new("application-autoscaling", true, 0x00003fffffffffff),
+    Service::new("applicationinsights", true, 0x0000003fefff8ffb),
+    Service::new("appmesh", true, 0x0000000febfe877b),
+    Service::new("apprunner", true, 0x00000002c1c20348),
+    Service::new("appstream2", true, 0x00000132e3f29358),
+    Service::new("appsync", true, 0x0000000fefffdffb),
+    Service::new("aps", true, 0x00000033ffffdfff),
+    Service::new("arc-zonal-shift", true, 0x00003fffffffffff),
+    Service::new("athena", true, 0x00003d7fffffffff),
+    Service::new("auditmanager", true, 0x00000003c0c28348),
+    Service::new("autoscaling", true, 0x00003fffffffffff),
+    Service::new("autoscaling-plans", true, 0x0000003fe9da877b),
+    Service::new("backup", true, 0x000039ffffffffff),
+    Service::new("backup-gateway", true, 0x00000033e9da837b),
+    Service::new("batch", true, 0x00003fffffffffff),
+    Service::new("bedrock", true, 0x00003973fffffffd),
+    Service::new("billingconductor", false, 0x00004003ffffffff),
+    Service::new("braket", true, 0x0000000340880000),
+    Service::new("budgets", false, 0x00005fcfffffffff),
+    Service::new("cases", true, 0x0000000240828319),
+    Service::new("cassandra", true, 0x0000003fedca835b),
+    Service::new("catalog.marketplace", true, 0x0000000040000000),
+    Service::new("ce", false, 0x00005bcfffffffff),
+    Service::new("chime", false, 0x00004003ffffffff),
+    Service::new("cleanrooms", true, 0x00000002c0ca0318),
+    Service::new("cloud9", true, 0x00000003ebda837b),
+    Service::new("cloudcontrolapi", true, 0x00003fffffffffff),
+    Service::new("clouddirectory", true, 0x00000022c0c28300),
+    Service::new("cloudformation", true, 0x00003fffffffffff),
+    Service::new("cloudfront", false, 0x0000400fffffffff),
+    Service::new("cloudhsm", true, 0x0000002040000000),
+    Service::new("cloudhsmv2", true, 0x00000033fffff7fb),
+    Service::new("cloudsearch", true, 0x0000000360420318),
+    Service::new("cloudtrail", true, 0x00003fffffffffff),
+    Service::new("cloudtrail-data", true, 0x00001c03edda877b),
+    Service::new("cloudwatch", true, 0x00003fffffffffff),
+    Service::new("codeartifact", true, 0x00000002c1da0348),
+    Service::new("codebuild", true, 0x000019ffeffefffb),
+    Service::new("codecatalyst", false, 0x00004003ffffffff),
+    Service::new("codecommit", true, 0x0000003fefda87fb),
+    Service::new("codedeploy", true, 0x00003fffffffffff),
+    Service::new("codeguru-profiler", true, 0x00000002c0ca0308),
+    Service::new("codeguru-reviewer", true, 0x00000002c0ca0308),
+    Service::new("codepipeline", true, 0x0000183feffe9ffb),
+    Service::new("codestar-connections", true, 0x00000013e1da8358),
+    Service::new("codestar-notifications", true, 0x00000003e9ca835a),
+    Service::new("cognito-identity", true, 0x00002037ffffffff),
+    Service::new("cognito-idp", true, 0x00002033ffffffff),
+    Service::new("cognito-sync", true, 0x00000002c0c20358),
+    Service::new("comprehend", true, 0x00001862c0c28358),
+    Service::new("comprehendmedical", true, 0x00000022c0c08200),
+    Service::new("compute-optimizer", true, 0x00003c3feffe8ffb),
+    Service::new("config", true, 0x00003fffffffffff),
+    Service::new("connect", true, 0x0000002240828319),
+    Service::new("connect-campaigns", true, 0x0000000240828319),
+    Service::new("contact-lens", true, 0x0000000240828358),
+    Service::new("controltower", true, 0x00002033ffffffff),
+    Service::new("cost-optimization-hub", true, 0x0000340040000000),
+    Service::new("cur", true, 0x0000000840000000),
+    Service::new("data-ats.iot", true, 0x0000003feffa935a),
+    Service::new("data.iot", true, 0x0000003fe9ca835a),
+    Service::new("data.jobs.iot", true, 0x0000003fedea935a),
+    Service::new("data.mediastore", true, 0x0000000240ca0218),
+    Service::new("databrew", true, 0x0000002fe1da835b),
+    Service::new("dataexchange", true, 0x00000003c0c20318),
+    Service::new("datapipeline", true, 0x0000004240400208),
+    Service::new("datasync", true, 0x000021ffffffffff),
+    Service::new("datazone", true, 0x0000203fffffffff),
+    Service::new("dax", true, 0x0000000fe1ea0348),
+    Service::new("detective", true, 0x00000033ebda835b),
+    Service::new("devicefarm", true, 0x0000000200000000),
+    Service::new("devops-guru", true, 0x00000003e1ca8358),
+    Service::new("directconnect", true, 0x00003fffffffffff),
+    Service::new("discovery", true, 0x0000000240c20208),
+    Service::new("dlm", true, 0x00003fffffffffff),
+    Service::new("dms", true, 0x00003fffffffffff),
+    Service::new("docdb", true, 0x0000002ae1c28358),
+    Service::new("drs", true, 0x00002033effe8ffb),
+    Service::new("ds", true, 0x00003dffffffdffb),
+    Service::new("dynamodb", true, 0x00003fffffffffff),
+    Service::new("ebs", true, 0x00003fffffffffff),
+    Service::new("ec2", true, 0x00003fffffffffff),
+    Service::new("ecr", true, 0x00003fffffffffff),
+    Service::new("ecr-public", true, 0x0000000240000000),
+    Service::new("ecs", true, 0x00003fffffffffff),
+    Service::new("edge.sagemaker", true, 0x00000002c0420008),
+    Service::new("eks", true, 0x00003fffffffffff),
+    Service::new("eks-auth", true, 0x0000203fffffffff),
+    Service::new("elasticache", true, 0x00003fffffffffff),
+    Service::new("elasticbeanstalk", true, 0x0000003feffffffb),
+    Service::new("elasticfilesystem", true, 0x00003dffffffffff),
+    Service::new("elasticloadbalancing", true, 0x00003fffffffffff),
+    Service::new("elasticmapreduce", true, 0x00003fffffffffff),
+    Service::new("emr-containers", true, 0x0000003feffe87fb),
+    Service::new("emr-serverless", true, 0x0000043fffffffff),
+    Service::new("entitlement.marketplace", true, 0x0000200840000000),
+    Service::new("es", true, 0x00003fffffffffff),
+    Service::new("events", true, 0x00003fffffffffff),
+    Service::new("execute-api", true, 0x000025ffffffffff),
+    Service::new("finspace", true, 0x00000002c0c28308),
+    Service::new("finspace-api", true, 0x00000002c0408000),
+    Service::new("firehose", true, 0x00003fffffffffff),
+    Service::new("fms", true, 0x0000003fffffffff),
+    Service::new("forecast", true, 0x00000002c0420358),
+    Service::new("forecastquery", true, 0x00000002c0420358),
+    Service::new("frauddetector", true, 0x00000002c0400300),
+    Service::new("fsx", true, 0x000039ffffffffff),
+    Service::new("gamelift", true, 0x0000000fe9dad37b),
+    Service::new("gameliftstreams", true, 0x0000203fffffffff),
+    Service::new("geo", true, 0x00000022e0ea9348),
+    Service::new("glacier", true, 0x000001ffe9da877b),
+    Service::new("globalaccelerator", true, 0x00000003ffffffff),
+    Service::new("glue", true, 0x00003d7fffffffff),
+    Service::new("grafana", true, 0x00000032c0c20318),
+    Service::new("greengrass", true, 0x00000036c0e29358),
+    Service::new("groundstation", true, 0x00000002e84a0311),
+    Service::new("guardduty", true, 0x0000397fffffffff),
+    Service::new("health", false, 0x0000414fffffffff),
+    Service::new("healthlake", true, 0x00000002c0c08240),
+    Service::new("iam", false, 0x00005bffffffffff),
+    Service::new("identity-chime", true, 0x0000000040020000),
+    Service::new("identitystore", true, 0x0000283fffffffff),
+    Service::new("importexport", false, 0x00004003ffffffff),
+    Service::new("ingest.timestream", true, 0x00000022c0420248),
+    Service::new("inspector", true, 0x00000033c0ca0258),
+    Service::new("inspector2", true, 0x0000003fffffdfff),
+    Service::new("internetmonitor", true, 0x0000203fffffffff),
+    Service::new("iot", true, 0x0000003feffa935a),
+    Service::new("iotdeviceadvisor", true, 0x0000000240400008),
+    Service::new("iotevents", true, 0x00000026c0c28358),
+    Service::new("ioteventsdata", true, 0x00000026c0c28358),
+    Service::new("iotfleetwise", true, 0x0000000040020040),
+    Service::new("iotsecuredtunneling", true, 0x0000003fe9ca835a),
+    Service::new("iotsitewise", true, 0x00000026c0428358),
+    Service::new("iotthingsgraph", true, 0x0000000240400218),
+    Service::new("iottwinmaker", true, 0x0000002640420358),
+    Service::new("iotwireless", true, 0x0000000260420208),
+    Service::new("ivs", true, 0x0000000240420058),
+    Service::new("ivschat", true, 0x0000000240420058),
+    Service::new("ivsrealtime", true, 0x0000000240420058),
+    Service::new("kafka", true, 0x0000203fffffffff),
+    Service::new("kafkaconnect", true, 0x0000003fffffffff),
+    Service::new("kendra", true, 0x00000022c0c08348),
+    Service::new("kendra-ranking", true, 0x0000203fff7dffff),
+    Service::new("kinesis", true, 0x00003fffffffffff),
+    Service::new("kinesisanalytics", true, 0x0000217fffffffff),
+    Service::new("kinesisvideo", true, 0x00000076e9e2935b),
+    Service::new("kms", true, 0x00003fffffffffff),
+    Service::new("lakeformation", true, 0x00003d7fffffffff),
+    Service::new("lambda", true, 0x00003fffffffffff),
+    Service::new("license-manager", true, 0x00003dffffffffff),
+    Service::new("license-manager-linux-subscriptions", true, 0x0000003fffffffff),
+    Service::new("license-manager-user-subscriptions", true, 0x00000033ffffdfff),
+    Service::new("lightsail", true, 0x00000002c1ca9758),
+    Service::new("logs", true, 0x00003fffffffffff),
+    Service::new("lookoutequipment", true, 0x0000000040400010),
+    Service::new("m2", true, 0x00000033e3fa8379),
+    Service::new("machinelearning", true, 0x0000000040400000),
+    Service::new("macie2", true, 0x00000003ebda837b),
+    Service::new("managedblockchain", true, 0x0000002040c00118),
+    Service::new("managedblockchain-query", true, 0x0000000040000000),
+    Service::new("marketplacecommerceanalytics", true, 0x0000000040000000),
+    Service::new("media-pipelines-chime", true, 0x0000000240828358),
+    Service::new("mediaconnect", true, 0x00000003e5ca9bfb),
+    Service::new("mediaconvert", true, 0x0000002be5ca9b79),
+    Service::new("medialive", true, 0x00000142e5ca9bf8),
+    Service::new("mediapackage", true, 0x00000143e1ca8b78),
+    Service::new("mediapackage-vod", true, 0x00000003e5ca8bf8),
+    Service::new("mediapackagev2", true, 0x00000003e5ca9bf8),
+    Service::new("mediastore", true, 0x0000000240ca0218),
+    Service::new("mediatailor", true, 0x00000002e5ca9bf9),
+    Service::new("meetings-chime", true, 0x0000003242828359),
+    Service::new("memory-db", true, 0x0000003fe1fa835a),
+    Service::new("messaging-chime", true, 0x0000000040020000),
+    Service::new("metering.marketplace", true, 0x0000213beffe8ffb),
+    Service::new("metrics.sagemaker", true, 0x00003d7fffffffff),
+    Service::new("mgh", true, 0x0000000240c20208),
+    Service::new("mgn", true, 0x00000033ffffffff),
+    Service::new("migrationhub-orchestrator", true, 0x0000000240c20208),
+    Service::new("migrationhub-strategy", true, 0x0000000240c20208),
+    Service::new("mobileanalytics", true, 0x0000000040000000),
+    Service::new("models-v2-lex", true, 0x0000002240c28319),
+    Service::new("models.lex", true, 0x0000002240c20308),
+    Service::new("mq", true, 0x0000007fffffffff),
+    Service::new("mturk-requester", false, 0x00004003ffffffff),
+    Service::new("neptune", true, 0x0000003fe9ca835a),
+    Service::new("network-firewall", true, 0x0000217fffffffff),
+    Service::new("networkmanager", false, 0x00004033ffffffff),
+    Service::new("notifications", true, 0x0000203fffffffff),
+    Service::new("notifications-contacts", false, 0x00004003ffffffff),
+    Service::new("nova-act", true, 0x0000000040000000),
+    Service::new("oam", true, 0x00003fffffffffff),
+    Service::new("oidc", true, 0x0000203fffffffff),
+    Service::new("omics", true, 0x0000000242c20110),
+    Service::new("organizations", false, 0x00005fffffffffff),
+    Service::new("osis", true, 0x00000003e0ea8358),
+    Service::new("outposts", true, 0x00000173fffa877b),
+    Service::new("participant.connect", true, 0x0000002240828319),
+    Service::new("partnercentral-channel", true, 0x0000000040000000),
+    Service::new("personalize", true, 0x00000006c0428358),
+    Service::new("pi", true, 0x00003fffffffffff),
+    Service::new("pinpoint", true, 0x00000022c0c28358),
+    Service::new("pipes", true, 0x00001c0fedfe87fb),
+    Service::new("polly", true, 0x0000202be9ee937b),
+    Service::new("portal.sso", true, 0x0000203fffffffff),
+    Service::new("pricing", true, 0x0000354840020040),
+    Service::new("profile", true, 0x0000000240828319),
+    Service::new("proton", true, 0x00000002c0c28318),
+    Service::new("qbusiness", true, 0x0000203fffffffff),
+    Service::new("query.timestream", true, 0x00000022c0420248),
+    Service::new("quicksight", true, 0x00001836e7fe9759),
+    Service::new("ram", true, 0x00003fffffffffff),
+    Service::new("rbin", true, 0x00003fffffffffff),
+    Service::new("rds", true, 0x00003fffffffffff),
+    Service::new("rds-data", true, 0x00000003c1c28358),
+    Service::new("redshift", true, 0x00003fffffffffff),
+    Service::new("redshift-serverless", true, 0x00001c3ff7ffffff),
+    Service::new("rekognition", true, 0x00001c23e2e28358),
+    Service::new("resiliencehub", true, 0x00000033e9da835b),
+    Service::new("resource-explorer-2", true, 0x00000033ffffffff),
+    Service::new("resource-groups", true, 0x00003fffffffffff),
+    Service::new("rolesanywhere", true, 0x0000383fffffffff),
+    Service::new("route53", false, 0x00005fffffffffff),
+    Service::new("route53-recovery-control-config", true, 0x0000000000000000),
+    Service::new("route53domains", true, 0x0000000040000000),
+    Service::new("route53profiles", true, 0x00003c3fffffffff),
+    Service::new("route53resolver", true, 0x00003fffffffffff),
+    Service::new("rum", true, 0x00002033ffffdffb),
+    Service::new("runtime-v2-lex", true, 0x0000002240c28319),
+    Service::new("runtime.lex", true, 0x0000002240c20308),
+    Service::new("runtime.sagemaker", true, 0x0000397fffffffff),
+    Service::new("s3", true, 0x00003fffffffffff),
+    Service::new("s3-control", true, 0x000021ffefff8ffb),
+    Service::new("s3-outposts", true, 0x00000173ebda877b),
+    Service::new("sagemaker", true, 0x0000397fffffffff),
+    Service::new("sagemaker-geospatial", true, 0x0000000200000000),
+    Service::new("savingsplans", false, 0x00005c0fffffffff),
+    Service::new("scheduler", true, 0x00003fffffffffff),
+    Service::new("schemas", true, 0x00001c3fedfe877b),
+    Service::new("sdb", true, 0x0000000360400308),
+    Service::new("secretsmanager", true, 0x00003fffffffffff),
+    Service::new("securityhub", true, 0x000023ffffffffff),
+    Service::new("securitylake", true, 0x00000033e1ca8378),
+    Service::new("serverlessrepo", true, 0x0000003fe9ca835a),
+    Service::new("servicecatalog", true, 0x0000043fefffaffb),
+    Service::new("servicecatalog-appregistry", true, 0x00000033efff8ffb),
+    Service::new("servicediscovery", true, 0x00003fffffffffff),
+    Service::new("servicequotas", true, 0x00003fffffffffff),
+    Service::new("ses", true, 0x00002033efdf97f9),
+    Service::new("shield", false, 0x00004003ffffffff),
+    Service::new("signer", true, 0x0000203fe9da835b),
+    Service::new("simspaceweaver", true, 0x00000032c04a0300),
+    Service::new("sms-voice", true, 0x00002033ffffaffd),
+    Service::new("snowball", true, 0x000001ffe7da877b),
+    Service::new("sns", true, 0x00003fffffffffff),
+    Service::new("sqs", true, 0x00003fffffffffff),
+    Service::new("ssm", true, 0x00003fffffffffff),
+    Service::new("ssm-contacts", true, 0x00000003e1ca8358),
+    Service::new("ssm-incidents", true, 0x00000003e1ca8358),
+    Service::new("ssm-quicksetup", true, 0x00000003e1ca8358),
+    Service::new("ssm-sap", true, 0x00000003effe8ffb),
+    Service::new("sso", true, 0x0000203fffffffff),
+    Service::new("states", true, 0x00003fffffffffff),
+    Service::new("storagegateway", true, 0x000021ffffffffff),
+    Service::new("streams.dynamodb", true, 0x00003fffffffffff),
+    Service::new("sts", true, 0x00003fffffffffff),
+    Service::new("support", true, 0x0000000000000000),
+    Service::new("supportapp", true, 0x0000000240400000),
+    Service::new("swf", true, 0x00003fffffffffff),
+    Service::new("synthetics", true, 0x00003fffffffffff),
+    Service::new("tagging", true, 0x00003fffffffffff),
+    Service::new("tax", false, 0x00004003ffffffff),
+    Service::new("textract", true, 0x00001873c1e28350),
+    Service::new("thinclient", true, 0x0000000240c28040),
+    Service::new("tnb", true, 0x00000002612a8210),
+    Service::new("transcribe", true, 0x0000187fe9ce835b),
+    Service::new("transcribestreaming", true, 0x0000187ef0c6d359),
+    Service::new("transfer", true, 0x0000203fffffffff),
+    Service::new("translate", true, 0x00001863c1ca835a),
+    Service::new("trustedadvisor", true, 0x00003402c0400210),
+    Service::new("verifiedpermissions", true, 0x0000003fffffffff),
+    Service::new("voice-chime", true, 0x0000000240c28318),
+    Service::new("voiceid", true, 0x0000000240828308),
+    Service::new("vpc-lattice", true, 0x00000003fdff8ffb),
+    Service::new("waf", false, 0x00004003ffffffff),
+    Service::new("waf-regional", true, 0x0000003feffe8ffb),
+    Service::new("wafv2", true, 0x0000217fffffffff),
+    Service::new("wellarchitected", true, 0x00000033e9ca835a),
+    Service::new("wisdom", true, 0x0000000240828318),
+    Service::new("workdocs", true, 0x0000000240400308),
+    Service::new("workmail", true, 0x0000000240400000),
+    Service::new("workspaces", true, 0x000001fae3c29359),
+    Service::new("workspaces-web", true, 0x0000000240c28348),
+    Service::new("xray", true, 0x00003fffffffffff),
+];
@@ -0,0 +1,290 @@
+//! `arn` command-line tool.
+//!
+//! ```text
+//! arn parse [--json] [ARN...]
+//! arn validate [--strict] [ARN...]
+//! arn match PATTERN [ARN...]
+//! ```
+//!
+//! ARNs are read one per line from stdin when none are given.
+
+use arn::{Arn, ArnField, ArnPattern, Component, validate::ArnValidator};
+use std::{
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+usage: arn parse [--json] [ARN...]
+       arn validate [--strict] [ARN...]
+       arn match PATTERN [ARN...]
+
+ARNs are read one per line from stdin when none are given.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stdout = io::stdout();
+    match run(&args, &mut stdout.lock()) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("arn: {message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &[String], out: &mut impl Write) -> Result<ExitCode, String> {
+    let (command, rest) = args.split_first().ok_or(USAGE)?;
+    let mut flags = Vec::new();
+    let mut operands = Vec::new();
+    for arg in rest {
+        if arg.starts_with("--") {
+            flags.push(arg.as_str());
+        } else {
+            operands.push(arg.clone());
+        }
+    }
+    let allowed: &[&str] = match command.as_str() {
+        "parse" => &["--json"],
+        "validate" => &["--strict"],
+        "match" => &[],
+        "help" | "--help" | "-h" => {
+            writeln!(out, "{USAGE}").map_err(|e| e.to_string())?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        _ => return Err(format!("unknown command '{command}'\n{USAGE}")),
+    };
+    if let Some(flag) = flags.iter().find(|f| !allowed.contains(f)) {
+        return Err(format!("unknown option '{flag}' for '{command}'"));
+    }
+
+    let pattern = if command == "match" {
+        if operands.is_empty() {
+            return Err(format!("missing PATTERN\n{USAGE}"));
+        }
+        let pattern = operands.remove(0);
+        Some(
+            pattern
+                .parse::<ArnPattern>()
+                .map_err(|e| format!("{pattern}: {e}"))?,
+        )
+    } else {
+        None
+    };
+
+    let inputs = if operands.is_empty() {
+        io::stdin()
+            .lock()
+            .lines()
+            .map(|line| line.map(|l| l.trim().to_string()))
+            .filter(|line| !matches!(line, Ok(l) if l.is_empty()))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.to_string())?
+    } else {
+        operands
+    };
+
+    let ok = match command.as_str() {
+        "parse" => parse(&inputs, flags.contains(&"--json"), out),
+        "validate" => validate(&inputs, flags.contains(&"--strict"), out),
+        _ => matches(&pattern.unwrap(), &inputs, out),
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn parse(inputs: &[String], json: bool, out: &mut impl Write) -> io::Result<bool> {
+    let mut ok = true;
+    for (i, input) in inputs.iter().enumerate() {
+        let result = input.parse::<Arn>();
+        if json {
+            let value = match &result {
+                Ok(arn) => {
+                    let mut object = serde_json::Map::new();
+                    object.insert("arn".into(), arn.to_string().into());
+                    for (field, value) in arn.components() {
+                        object.insert(field.as_ref().into(), value.into());
+                    }
+                    serde_json::Value::Object(object)
+                }
+                Err(e) => serde_json::json!({ "input": input, "error": e.to_string() }),
+            };
+            writeln!(out, "{value}")?;
+        } else {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            match &result {
+                Ok(arn) => {
+                    writeln!(out, "{arn}")?;
+                    for line in format!("{arn:#}").lines() {
+                        writeln!(out, "  {line}")?;
+                    }
+                }
+                Err(e) => writeln!(out, "{input}\n  error: {e}")?,
+            }
+        }
+        ok &= result.is_ok();
+    }
+    Ok(ok)
+}
+
+/// Problems a successfully parsed ARN can still have when `--strict` is set,
+/// including every error from `validator`.
+fn strict_problems(input: &str, arn: &Arn, validator: &ArnValidator) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !input.starts_with("arn:") {
+        problems.push("does not start with 'arn:'".to_string());
+    }
+    for (field, value) in arn.components() {
+        if value.is_some_and(|v| v.contains(['*', '?'])) {
+            problems.push(format!("{} contains a wildcard", field.as_ref()));
+        }
+    }
+    match arn.get(ArnField::Service) {
+        Component::None => problems.push("service is empty".to_string()),
+        Component::Value(service)
+            if !service
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') =>
+        {
+            problems.push(format!("service '{service}' is not lowercase alphanumeric"));
+        }
+        _ => {}
+    }
+    if arn.resource_id == Component::None {
+        problems.push("resource is empty".to_string());
+    }
+    problems.extend(
+        validator
+            .validate_all(arn)
+            .into_iter()
+            .map(|e| e.to_string()),
+    );
+    problems
+}
+
+fn validate(inputs: &[String], strict: bool, out: &mut impl Write) -> io::Result<bool> {
+    let validator = ArnValidator::with_builtins();
+    let mut ok = true;
+    for input in inputs {
+        let problems = match input.parse::<Arn>() {
+            Ok(arn) if strict => strict_problems(input, &arn, &validator),
+            Ok(_) => Vec::new(),
+            Err(e) => vec![e.to_string()],
+        };
+        if problems.is_empty() {
+            writeln!(out, "ok\t{input}")?;
+        } else {
+            writeln!(out, "invalid\t{input}\t{}", problems.join("; "))?;
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+/// Prints the ARNs matching `pattern`, like `grep`; unparsable input is
+/// reported on stderr and never matches.
+fn matches(pattern: &ArnPattern, inputs: &[String], out: &mut impl Write) -> io::Result<bool> {
+    let mut any = false;
+    for input in inputs {
+        match input.parse::<Arn>() {
+            Ok(arn) if pattern.matches(&arn) => {
+                writeln!(out, "{input}")?;
+                any = true;
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("arn: {input}: {e}"),
+        }
+    }
+    Ok(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> (Result<ExitCode, String>, String) {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let mut out = Vec::new();
+        let code = run(&args, &mut out);
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        let (code, out) = run_args(&["parse", "arn:aws:iam::123456789012:role/r"]);
+        assert_eq!(code, Ok(ExitCode::SUCCESS));
+        assert_eq!(
+            out,
+            "arn:aws:iam::123456789012:role/r\n  partition: aws\n  service:   iam\n  \
+             region:    (none)\n  account:   123456789012\n  resource:  role/r\n"
+        );
+        let arn: Arn = "arn:aws:iam::123456789012:role/r".parse().unwrap();
+        let breakdown: String = format!("{arn:#}")
+            .lines()
+            .map(|line| format!("  {line}\n"))
+            .collect();
+        assert_eq!(out, format!("{arn}\n{breakdown}"));
+
+        let (code, out) = run_args(&["parse", "--json", "arn:aws:s3:::bucket", "nope"]);
+        assert_eq!(code, Ok(ExitCode::FAILURE));
+        let lines: Vec<serde_json::Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines[0]["service"], "s3");
+        assert_eq!(lines[0]["region"], serde_json::Value::Null);
+        assert_eq!(lines[1]["input"], "nope");
+    }
+
+    #[test]
+    fn test_validate() {
+        let loose = "arn:aws:s3:::*";
+        let (code, _) = run_args(&["validate", loose]);
+        assert_eq!(code, Ok(ExitCode::SUCCESS));
+        let (code, out) = run_args(&["validate", "--strict", loose, "arn:aws:s3:::bucket"]);
+        assert_eq!(code, Ok(ExitCode::FAILURE));
+        assert_eq!(
+            out,
+            "invalid\tarn:aws:s3:::*\tresource contains a wildcard\nok\tarn:aws:s3:::bucket\n"
+        );
+
+        let arn = "xyz:aws:S3::1234:".parse().unwrap();
+        assert_eq!(
+            strict_problems("xyz:aws:S3::1234:", &arn, &ArnValidator::with_builtins()),
+            [
+                "does not start with 'arn:'",
+                "service 'S3' is not lowercase alphanumeric",
+                "resource is empty",
+                "Invalid region: missing",
+                "Invalid account: '1234' is not a 12-digit account ID",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_match() {
+        let (code, out) = run_args(&[
+            "match",
+            "arn:aws:s3:::logs-*",
+            "arn:aws:s3:::logs-2024",
+            "arn:aws:s3:::data",
+        ]);
+        assert_eq!(code, Ok(ExitCode::SUCCESS));
+        assert_eq!(out, "arn:aws:s3:::logs-2024\n");
+
+        let (code, _) = run_args(&["match", "arn:aws:s3:::logs-*", "arn:aws:s3:::data"]);
+        assert_eq!(code, Ok(ExitCode::FAILURE));
+        assert!(run_args(&["match"]).0.is_err());
+        assert!(
+            run_args(&["parse", "--strict", "arn:aws:s3:::b"])
+                .0
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Arn, Component};
+
+const DOMAIN: &str = ".amazonaws.com";
+
+// ARN service prefixes whose CloudTrail event source uses a different name.
+const EXCEPTIONS: &[(&str, &str)] = &[("cloudwatch", "monitoring"), ("mq", "amazonmq")];
+
+// Prefixes logged under another service's event source, which maps back to
+// that service rather than to them.
+const SHARED: &[(&str, &str)] = &[("execute-api", "apigateway")];
+
+/// The CloudTrail `eventSource` for an ARN service prefix, e.g. `s3` becomes
+/// `s3.amazonaws.com`.
+pub fn event_source(service: &str) -> String {
+    let name = EXCEPTIONS
+        .iter()
+        .chain(SHARED)
+        .find(|(s, _)| *s == service)
+        .map_or(service, |(_, source)| source);
+    format!("{name}{DOMAIN}")
+}
+
+/// The ARN service prefix for a CloudTrail `eventSource`, the inverse of
+/// [`event_source`]. Returns `None` if `source` isn't an `amazonaws.com` domain.
+pub fn service_for_event_source(source: &str) -> Option<&str> {
+    let name = source
+        .strip_suffix(DOMAIN)
+        .filter(|name| !name.is_empty())?;
+    Some(
+        EXCEPTIONS
+            .iter()
+            .find(|(_, s)| *s == name)
+            .map_or(name, |(service, _)| service),
+    )
+}
+
+impl Arn {
+    pub fn event_source(&self) -> Option<String> {
+        match &self.service {
+            Component::Value(service) => Some(event_source(service)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_source() {
+        assert_eq!(event_source("s3"), "s3.amazonaws.com");
+        assert_eq!(event_source("cloudwatch"), "monitoring.amazonaws.com");
+        assert_eq!(event_source("execute-api"), "apigateway.amazonaws.com");
+        assert_eq!(event_source("apigateway"), "apigateway.amazonaws.com");
+        assert_eq!(event_source("mq"), "amazonmq.amazonaws.com");
+
+        let arn: Arn = "arn:aws:lambda:us-east-1:123456789012:function:f"
+            .parse()
+            .unwrap();
+        assert_eq!(arn.event_source().unwrap(), "lambda.amazonaws.com");
+        assert_eq!(Arn::ANY.event_source(), None);
+    }
+
+    #[test]
+    fn test_service_for_event_source() {
+        assert_eq!(service_for_event_source("s3.amazonaws.com"), Some("s3"));
+        assert_eq!(
+            service_for_event_source("monitoring.amazonaws.com"),
+            Some("cloudwatch")
+        );
+        assert_eq!(
+            service_for_event_source("apigateway.amazonaws.com"),
+            Some("apigateway")
+        );
+        assert_eq!(
+            service_for_event_source("amazonmq.amazonaws.com"),
+            Some("mq")
+        );
+        assert_eq!(service_for_event_source("example.com"), None);
+        assert_eq!(service_for_event_source(".amazonaws.com"), None);
+    }
+}
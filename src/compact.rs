@@ -0,0 +1,207 @@
+use crate::{Arn, ArnField, ArnParseError, Component, ComponentRef, Partition, Region};
+use std::{fmt, str::FromStr};
+
+const CAPACITY: usize = 91;
+// Length marker for a wildcard component; real lengths never exceed CAPACITY.
+const ANY: u8 = u8::MAX;
+
+/// An [`Arn`] packed into 96 bytes (against 136), for holding millions of
+/// ARNs in memory.
+///
+/// The service, account, and resource share one inline buffer, so they are
+/// limited to 91 bytes combined rather than 32, 12, and 64 separately;
+/// converting a longer [`Arn`] fails with
+/// [`CompactTooLong`](ArnParseError::CompactTooLong). The size is
+/// guaranteed and won't change without a major version bump.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct CompactArn {
+    partition: Partition,
+    region: Component<Region>,
+    // Service, account, and resource lengths; 0 for none.
+    lens: [u8; 3],
+    buf: [u8; CAPACITY],
+}
+
+const _: () = assert!(std::mem::size_of::<CompactArn>() == 96);
+
+const FIELDS: [ArnField; 3] = [ArnField::Service, ArnField::Account, ArnField::Resource];
+
+impl CompactArn {
+    pub fn partition(&self) -> Partition {
+        self.partition
+    }
+
+    pub fn region(&self) -> Component<Region> {
+        self.region
+    }
+
+    /// The same as [`Arn::get`], without unpacking.
+    pub fn get(&self, field: ArnField) -> ComponentRef<'_> {
+        match field {
+            ArnField::Partition => Component::Value(self.partition.as_ref()),
+            ArnField::Region => match &self.region {
+                Component::None => Component::None,
+                Component::Any => Component::Any,
+                Component::Value(r) => Component::Value(r.as_ref()),
+            },
+            _ => {
+                let i = FIELDS.iter().position(|f| *f == field).unwrap();
+                let start: usize = self.lens[..i]
+                    .iter()
+                    .filter(|len| **len != ANY)
+                    .map(|len| *len as usize)
+                    .sum();
+                match self.lens[i] {
+                    0 => Component::None,
+                    ANY => Component::Any,
+                    len => Component::Value(
+                        std::str::from_utf8(&self.buf[start..start + len as usize]).unwrap(),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&Arn> for CompactArn {
+    type Error = ArnParseError;
+
+    fn try_from(arn: &Arn) -> Result<Self, Self::Error> {
+        let mut compact = CompactArn {
+            partition: arn.partition,
+            region: arn.region,
+            lens: [0; 3],
+            buf: [0; CAPACITY],
+        };
+        let mut end = 0;
+        for (i, field) in FIELDS.iter().enumerate() {
+            compact.lens[i] = match arn.get(*field) {
+                Component::None => 0,
+                Component::Any => ANY,
+                Component::Value(value) => {
+                    let bytes = value.as_bytes();
+                    let slot = compact
+                        .buf
+                        .get_mut(end..end + bytes.len())
+                        .ok_or(ArnParseError::CompactTooLong)?;
+                    slot.copy_from_slice(bytes);
+                    end += bytes.len();
+                    bytes.len() as u8
+                }
+            };
+        }
+        Ok(compact)
+    }
+}
+
+impl TryFrom<Arn> for CompactArn {
+    type Error = ArnParseError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        Self::try_from(&arn)
+    }
+}
+
+impl From<&CompactArn> for Arn {
+    fn from(compact: &CompactArn) -> Self {
+        let mut arn = Arn {
+            partition: compact.partition,
+            region: compact.region,
+            ..Arn::default()
+        };
+        for field in FIELDS {
+            // Each component came from a valid `Arn`, so it fits.
+            arn.set(field, compact.get(field)).unwrap();
+        }
+        arn
+    }
+}
+
+impl From<CompactArn> for Arn {
+    fn from(compact: CompactArn) -> Self {
+        Arn::from(&compact)
+    }
+}
+
+impl FromStr for CompactArn {
+    type Err = ArnParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(&value.parse::<Arn>()?)
+    }
+}
+
+impl fmt::Display for CompactArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Arn::from(self), f)
+    }
+}
+
+impl fmt::Debug for CompactArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for s in [
+            "arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST",
+            "arn:aws:iam::123456789012:role/r",
+            "arn:aws-cn:s3:::bucket",
+            "arn:aws:::::",
+        ] {
+            let arn: Arn = s.parse().unwrap();
+            let compact = CompactArn::try_from(&arn).unwrap();
+            assert_eq!(Arn::from(compact), arn);
+            assert_eq!(compact.to_string(), s);
+            for field in [
+                ArnField::Partition,
+                ArnField::Service,
+                ArnField::Region,
+                ArnField::Account,
+                ArnField::Resource,
+            ] {
+                assert_eq!(compact.get(field), arn.get(field));
+            }
+        }
+        let any = CompactArn::try_from(Arn::ANY).unwrap();
+        assert_eq!(Arn::from(any), Arn::ANY);
+        assert_eq!(any.get(ArnField::Account), Component::Any);
+        assert_eq!(any.region(), Component::Any);
+    }
+
+    #[test]
+    fn test_too_long() {
+        let full = format!(
+            "arn:aws:{}:us-east-1:123456789012:{}",
+            "s".repeat(32),
+            "r".repeat(64)
+        );
+        assert!(matches!(
+            full.parse::<CompactArn>(),
+            Err(ArnParseError::CompactTooLong)
+        ));
+        let fits = format!(
+            "arn:aws:{}:us-east-1:123456789012:{}",
+            "s".repeat(15),
+            "r".repeat(64)
+        );
+        assert_eq!(fits.parse::<CompactArn>().unwrap().to_string(), fits);
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        let a: CompactArn = "arn:aws:s3:::ab".parse().unwrap();
+        let b: CompactArn = "arn:aws:s3:::a".parse().unwrap();
+        assert_ne!(a, b);
+        assert_eq!(a, "arn:aws:s3:::ab".parse().unwrap());
+        // "s3" + "" + "ab" and "s3a" + "" + "b" share bytes but not lengths.
+        let c: CompactArn = "arn:aws:s3a:::b".parse().unwrap();
+        assert_ne!(a, c);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{Arn, ArnParseError, Component, Partition, Region};
+use arrayvec::ArrayString;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// Console hosts by partition. The isolated partitions have no public
+/// console.
+const CONSOLES: &[(Partition, &str)] = &[
+    (Partition::Aws, "console.aws.amazon.com"),
+    (Partition::AwsCn, "console.amazonaws.cn"),
+    (Partition::AwsUsGov, "console.amazonaws-us-gov.com"),
+];
+
+fn console_host(partition: Partition) -> Option<&'static str> {
+    CONSOLES
+        .iter()
+        .find(|(p, _)| *p == partition)
+        .map(|(_, host)| *host)
+}
+
+// The partition and subdomain (region or service, possibly empty) of a
+// console host.
+fn parse_host(host: &str) -> Option<(Partition, &str)> {
+    CONSOLES.iter().find_map(|(partition, console)| {
+        let subdomain = host.strip_suffix(console)?;
+        match subdomain.strip_suffix('.') {
+            Some(subdomain) => Some((*partition, subdomain)),
+            None if subdomain.is_empty() => Some((*partition, "")),
+            None => None,
+        }
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum ConsoleUrlError {
+    #[error("Not an AWS console URL: {0}")]
+    NotConsole(String),
+    #[error("Unrecognized console URL: {0}")]
+    Unrecognized(String),
+    #[error(transparent)]
+    Arn(#[from] ArnParseError),
+}
+
+fn encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => write!(out, "%{b:02X}").unwrap(),
+        }
+    }
+    out
+}
+
+fn decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+}
+
+fn s3_arn(partition: Partition, resource: &str) -> Result<Arn, ArnParseError> {
+    format!("arn:{}:s3:::{resource}", partition.as_ref()).parse()
+}
+
+impl Arn {
+    /// Extracts the resource referenced by an AWS console URL, as produced by
+    /// [`Arn::console_url`] or copied from the browser. Components the URL
+    /// doesn't carry (usually the account) are left as [`Component::None`].
+    pub fn from_console_url(url: &str) -> Result<Arn, ConsoleUrlError> {
+        let unrecognized = || ConsoleUrlError::Unrecognized(url.to_string());
+
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or_else(|| ConsoleUrlError::NotConsole(url.to_string()))?;
+        let (host, rest) = rest.split_once('/').unwrap_or((rest, ""));
+        let (partition, subdomain) =
+            parse_host(host).ok_or_else(|| ConsoleUrlError::NotConsole(url.to_string()))?;
+        let (rest, fragment) = rest.split_once('#').unwrap_or((rest, ""));
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let region = match query_param(query, "region").unwrap_or(subdomain) {
+            "" | "s3" => Component::None,
+            r => Component::Value(
+                r.parse::<Region>()
+                    .map_err(|_| ArnParseError::InvalidRegion(r.to_string()))?,
+            ),
+        };
+
+        let (service, resource) = match path.split('/').collect::<Vec<_>>().as_slice() {
+            ["go", "view"] => {
+                let arn = query_param(query, "arn").ok_or_else(unrecognized)?;
+                return Ok(decode(arn).parse()?);
+            }
+            ["s3", "buckets", bucket] => {
+                return Ok(s3_arn(partition, &decode(bucket))?);
+            }
+            ["s3", "object", bucket] => {
+                let key = query_param(query, "prefix").ok_or_else(unrecognized)?;
+                return Ok(s3_arn(
+                    partition,
+                    &format!("{}/{}", decode(bucket), decode(key)),
+                )?);
+            }
+            ["lambda", "home"] => {
+                let name = fragment
+                    .strip_prefix("/functions/")
+                    .ok_or_else(unrecognized)?;
+                let name = name.split(['/', '?']).next().unwrap_or(name);
+                ("lambda", format!("function:{}", decode(name)))
+            }
+            ["iam", "home"] => match fragment.split('/').collect::<Vec<_>>().as_slice() {
+                ["", "roles", name, ..] => ("iam", format!("role/{}", decode(name))),
+                ["", "users", name, ..] => ("iam", format!("user/{}", decode(name))),
+                _ => return Err(unrecognized()),
+            },
+            ["dynamodbv2", "home"] => {
+                let (_, query) = fragment.split_once('?').ok_or_else(unrecognized)?;
+                let name = query_param(query, "name").ok_or_else(unrecognized)?;
+                ("dynamodb", format!("table/{}", decode(name)))
+            }
+            ["cloudwatch", "home"] => {
+                // Log group names are double-encoded with `$` standing in for `%`.
+                let name = fragment
+                    .strip_prefix("logsV2:log-groups/log-group/")
+                    .ok_or_else(unrecognized)?;
+                let name = name.split('/').next().unwrap_or(name);
+                (
+                    "logs",
+                    format!("log-group:{}", decode(&decode(&name.replace('$', "%")))),
+                )
+            }
+            _ => return Err(unrecognized()),
+        };
+
+        Ok(Arn {
+            partition,
+            service: Component::Value(ArrayString::from(service).unwrap()),
+            region,
+            account: Component::None,
+            resource_id: Component::Value(
+                ArrayString::from(&resource).map_err(|_| ArnParseError::ResourceIdTooLong)?,
+            ),
+        })
+    }
+
+    /// A link to the AWS console page for this resource, on the console of
+    /// its partition, falling back to the console's generic ARN lookup for
+    /// services without a dedicated page.
+    ///
+    /// Returns `None` if the service or resource is missing or a wildcard,
+    /// or if the partition has no public console.
+    pub fn console_url(&self) -> Option<String> {
+        let Component::Value(service) = &self.service else {
+            return None;
+        };
+        let Component::Value(resource) = &self.resource_id else {
+            return None;
+        };
+        let console = console_host(self.partition)?;
+        let region = match &self.region {
+            Component::Value(region) => Some(region.as_ref()),
+            _ => None,
+        };
+        let host = match region {
+            Some(region) => format!("https://{region}.{console}"),
+            None => format!("https://{console}"),
+        };
+        let query = region.map(|r| format!("?region={r}")).unwrap_or_default();
+
+        // Only bucket and object ARNs have neither region nor account; access
+        // points, Object Lambda and Outposts ARNs take the fallback.
+        let bucket = self.region == Component::None && self.account == Component::None;
+        let url = match (service.as_str(), resource.split_once(['/', ':'])) {
+            ("s3", None) if bucket => {
+                format!("https://s3.{console}/s3/buckets/{}", encode(resource))
+            }
+            ("s3", Some((bucket_name, key))) if bucket => format!(
+                "https://s3.{console}/s3/object/{}?prefix={}",
+                encode(bucket_name),
+                encode(key)
+            ),
+            ("lambda", Some(("function", rest))) => {
+                let name = rest.split(':').next().unwrap_or(rest);
+                format!("{host}/lambda/home{query}#/functions/{}", encode(name))
+            }
+            ("iam", Some(("role", path))) => {
+                let name = path.rsplit('/').next().unwrap_or(path);
+                format!("https://{console}/iam/home#/roles/{}", encode(name))
+            }
+            ("iam", Some(("user", path))) => {
+                let name = path.rsplit('/').next().unwrap_or(path);
+                format!("https://{console}/iam/home#/users/{}", encode(name))
+            }
+            ("dynamodb", Some(("table", rest))) => {
+                let name = rest.split('/').next().unwrap_or(rest);
+                format!("{host}/dynamodbv2/home{query}#table?name={}", encode(name))
+            }
+            _ => format!(
+                "https://{console}/go/view?arn={}",
+                encode(&self.to_string())
+            ),
+        };
+
+        Some(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(arn: &str) -> Option<String> {
+        arn.parse::<Arn>().unwrap().console_url()
+    }
+
+    #[test]
+    fn test_console_url_s3() {
+        assert_eq!(
+            url("arn:aws:s3:::my-bucket").unwrap(),
+            "https://s3.console.aws.amazon.com/s3/buckets/my-bucket"
+        );
+        assert_eq!(
+            url("arn:aws:s3:::my-bucket/logs/a b.txt").unwrap(),
+            "https://s3.console.aws.amazon.com/s3/object/my-bucket?prefix=logs%2Fa%20b.txt"
+        );
+    }
+
+    #[test]
+    fn test_console_url_s3_access_point() {
+        assert_eq!(
+            url("arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap").unwrap(),
+            "https://console.aws.amazon.com/go/view?arn=arn%3Aaws%3As3%3Aus-west-2%3A123456789012%3Aaccesspoint%2Fmy-ap"
+        );
+        assert!(
+            url("arn:aws:s3-object-lambda:us-west-2:123456789012:accesspoint/olap")
+                .unwrap()
+                .contains("/go/view?")
+        );
+        assert!(
+            url("arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-1/bucket/b")
+                .unwrap()
+                .contains("/go/view?")
+        );
+    }
+
+    #[test]
+    fn test_console_url_lambda() {
+        assert_eq!(
+            url("arn:aws:lambda:eu-west-1:123456789012:function:my-function:$LATEST").unwrap(),
+            "https://eu-west-1.console.aws.amazon.com/lambda/home?region=eu-west-1#/functions/my-function"
+        );
+    }
+
+    #[test]
+    fn test_console_url_iam() {
+        assert_eq!(
+            url("arn:aws:iam::123456789012:role/service/my-role").unwrap(),
+            "https://console.aws.amazon.com/iam/home#/roles/my-role"
+        );
+        assert_eq!(
+            url("arn:aws:iam::123456789012:user/alice").unwrap(),
+            "https://console.aws.amazon.com/iam/home#/users/alice"
+        );
+    }
+
+    #[test]
+    fn test_console_url_fallback() {
+        assert_eq!(
+            url("arn:aws:sns:us-east-1:123456789012:topic").unwrap(),
+            "https://console.aws.amazon.com/go/view?arn=arn%3Aaws%3Asns%3Aus-east-1%3A123456789012%3Atopic"
+        );
+    }
+
+    #[test]
+    fn test_console_url_partitions() {
+        assert_eq!(
+            url("arn:aws-cn:lambda:cn-north-1:123456789012:function:f").unwrap(),
+            "https://cn-north-1.console.amazonaws.cn/lambda/home?region=cn-north-1#/functions/f"
+        );
+        assert_eq!(
+            url("arn:aws-cn:s3:::my-bucket").unwrap(),
+            "https://s3.console.amazonaws.cn/s3/buckets/my-bucket"
+        );
+        assert_eq!(
+            url("arn:aws-us-gov:iam::123456789012:role/r").unwrap(),
+            "https://console.amazonaws-us-gov.com/iam/home#/roles/r"
+        );
+        assert_eq!(
+            url("arn:aws-us-gov:sns:us-gov-west-1:123456789012:topic").unwrap(),
+            "https://console.amazonaws-us-gov.com/go/view?arn=arn%3Aaws-us-gov%3Asns%3Aus-gov-west-1%3A123456789012%3Atopic"
+        );
+        assert_eq!(url("arn:aws-iso:s3:::my-bucket"), None);
+        assert!(
+            CONSOLES
+                .iter()
+                .all(|(partition, host)| console_host(*partition) == Some(*host))
+        );
+    }
+
+    fn parse(url: &str) -> String {
+        Arn::from_console_url(url).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_from_console_url_roundtrip() {
+        for arn in [
+            "arn:aws:s3:::my-bucket",
+            "arn:aws:s3:::my-bucket/logs/a b.txt",
+            "arn:aws:lambda:eu-west-1::function:my-function",
+            "arn:aws:iam:::role/my-role",
+            "arn:aws:dynamodb:us-west-2::table/orders",
+            "arn:aws:sns:us-east-1:123456789012:topic",
+        ] {
+            let url = url(arn).unwrap();
+            assert_eq!(parse(&url), arn, "{url}");
+        }
+    }
+
+    #[test]
+    fn test_from_console_url_browser_forms() {
+        assert_eq!(
+            parse(
+                "https://s3.console.aws.amazon.com/s3/buckets/my-bucket?region=us-east-1&tab=objects"
+            ),
+            "arn:aws:s3:::my-bucket"
+        );
+        assert_eq!(
+            parse("https://s3.console.amazonaws.cn/s3/buckets/my-bucket?region=cn-north-1"),
+            "arn:aws-cn:s3:::my-bucket"
+        );
+        assert_eq!(
+            parse("https://console.amazonaws-us-gov.com/iam/home#/roles/r"),
+            "arn:aws-us-gov:iam:::role/r"
+        );
+        assert_eq!(
+            parse(
+                "https://cn-north-1.console.amazonaws.cn/lambda/home?region=cn-north-1#/functions/f"
+            ),
+            "arn:aws-cn:lambda:cn-north-1::function:f"
+        );
+        assert_eq!(
+            parse(
+                "https://us-east-1.console.aws.amazon.com/lambda/home?region=us-east-1#/functions/my-function?tab=code"
+            ),
+            "arn:aws:lambda:us-east-1::function:my-function"
+        );
+        assert_eq!(
+            parse(
+                "https://eu-central-1.console.aws.amazon.com/cloudwatch/home?region=eu-central-1#logsV2:log-groups/log-group/$252Faws$252Flambda$252Ffoo"
+            ),
+            "arn:aws:logs:eu-central-1::log-group:/aws/lambda/foo"
+        );
+    }
+
+    #[test]
+    fn test_from_console_url_errors() {
+        assert!(matches!(
+            Arn::from_console_url("https://example.com/s3/buckets/x"),
+            Err(ConsoleUrlError::NotConsole(_))
+        ));
+        assert!(matches!(
+            Arn::from_console_url("https://evilconsole.aws.amazon.com/s3/buckets/x"),
+            Err(ConsoleUrlError::NotConsole(_))
+        ));
+        assert!(matches!(
+            Arn::from_console_url("https://console.aws.amazon.com/billing/home"),
+            Err(ConsoleUrlError::Unrecognized(_))
+        ));
+        assert!(matches!(
+            Arn::from_console_url("https://mars-1.console.aws.amazon.com/lambda/home#/functions/f"),
+            Err(ConsoleUrlError::Arn(ArnParseError::InvalidRegion(_)))
+        ));
+    }
+
+    #[test]
+    fn test_console_url_wildcard() {
+        assert_eq!(Arn::ANY.console_url(), None);
+        assert_eq!(url("arn:aws:s3:::"), None);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Arn, ArnParseError, Component, Partition, Region, global, validate::is_account_id};
+use arrayvec::ArrayString;
+
+/// Defaults for the components a partial identifier leaves out, usually the
+/// caller's own partition, region and account.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct ArnContext {
+    pub partition: Partition,
+    pub region: Component<Region>,
+    pub account: Component<ArrayString<12>>,
+}
+
+impl ArnContext {
+    pub fn new(region: Region, account: &str) -> Result<Self, ArnParseError> {
+        Ok(ArnContext {
+            partition: region.partition(),
+            region: Component::Value(region),
+            account: Component::Value(
+                ArrayString::from(account).map_err(|_| ArnParseError::AccountTooLong)?,
+            ),
+        })
+    }
+}
+
+// A leading `{account}:` as in `123456789012:my-topic`.
+fn split_account(input: &str) -> (Option<&str>, &str) {
+    match input.split_once(':') {
+        Some((account, rest)) if is_account_id(account) => (Some(account), rest),
+        _ => (None, input),
+    }
+}
+
+impl Arn {
+    /// Completes a partial identifier for `service` the way the AWS CLI
+    /// does, taking missing components from `ctx`. Full ARNs are parsed
+    /// unchanged.
+    ///
+    /// Accepted forms are a bare name (`my-function`, `my-topic`), a name
+    /// with its account (`123456789012:my-topic`), and for Lambda a partial
+    /// ARN (`123456789012:function:my-function`) or qualified name
+    /// (`my-function:live`). Global services get no region and S3 gets
+    /// neither region nor account.
+    pub fn resolve_partial(
+        input: &str,
+        ctx: &ArnContext,
+        service: &str,
+    ) -> Result<Arn, ArnParseError> {
+        if input.starts_with("arn:") {
+            return input.parse();
+        }
+
+        let (account, rest) = split_account(input);
+        let resource = match service {
+            "lambda" if rest.starts_with("function:") => rest.to_string(),
+            "lambda" => format!("function:{rest}"),
+            _ => rest.to_string(),
+        };
+
+        let mut arn = Arn {
+            partition: ctx.partition,
+            region: ctx.region,
+            account: ctx.account,
+            ..Arn::default()
+        };
+        arn.set_service(service)?;
+        arn.set_resource_id(&resource)?;
+        if let Some(account) = account {
+            arn.set_account(account)?;
+        }
+        if global::is_global_service(service) || service == "s3" {
+            arn.region = Component::None;
+        }
+        if !global::requires_account(service) {
+            arn.account = Component::None;
+        }
+        Ok(arn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_partial() {
+        let ctx = ArnContext::new(Region::EuWest1, "123456789012").unwrap();
+        let resolve = |input: &str, service: &str| {
+            Arn::resolve_partial(input, &ctx, service)
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            resolve("my-function", "lambda"),
+            "arn:aws:lambda:eu-west-1:123456789012:function:my-function"
+        );
+        assert_eq!(
+            resolve("my-function:live", "lambda"),
+            "arn:aws:lambda:eu-west-1:123456789012:function:my-function:live"
+        );
+        assert_eq!(
+            resolve("210987654321:function:f", "lambda"),
+            "arn:aws:lambda:eu-west-1:210987654321:function:f"
+        );
+        assert_eq!(
+            resolve("210987654321:alerts", "sns"),
+            "arn:aws:sns:eu-west-1:210987654321:alerts"
+        );
+        assert_eq!(
+            resolve("role/deploy", "iam"),
+            "arn:aws:iam::123456789012:role/deploy"
+        );
+        assert_eq!(resolve("my-bucket", "s3"), "arn:aws:s3:::my-bucket");
+        assert_eq!(
+            resolve("arn:aws:sqs:us-east-1:123456789012:q", "sns"),
+            "arn:aws:sqs:us-east-1:123456789012:q"
+        );
+    }
+
+    #[test]
+    fn test_context() {
+        let ctx = ArnContext::new(Region::CnNorth1, "123456789012").unwrap();
+        assert_eq!(ctx.partition, Partition::AwsCn);
+        assert!(matches!(
+            ArnContext::new(Region::UsEast1, "1234567890123"),
+            Err(ArnParseError::AccountTooLong)
+        ));
+
+        let empty = ArnContext::default();
+        let arn = Arn::resolve_partial("queue", &empty, "sqs").unwrap();
+        assert_eq!(arn.to_string(), "arn:aws:sqs:::queue");
+    }
+}
@@ -0,0 +1,189 @@
+//! ECR repository ARNs and their registry URIs.
+
+use crate::{
+    Arn, ArnField, Component, Region,
+    typed::{resource, resource_of},
+    validate::{ValidationError, is_account_id},
+};
+
+const SERVICE: &str = "ecr";
+
+fn check_name(name: &str) -> Result<(), ValidationError> {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b"._-/".contains(&b));
+    if !valid {
+        return Err(ValidationError::invalid(
+            ArnField::Resource,
+            format!("'{name}' is not a repository name"),
+        ));
+    }
+    Ok(())
+}
+
+/// `arn:aws:ecr:{region}:{account}:repository/{name}`, which corresponds to
+/// the registry URI `{account}.dkr.ecr.{region}.amazonaws.com/{name}` (with
+/// the partition's DNS suffix outside `aws`).
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct EcrRepositoryArn(Arn);
+
+typed_arn!(EcrRepositoryArn);
+
+impl EcrRepositoryArn {
+    pub fn new(region: Region, account: &str, name: &str) -> Result<Self, ValidationError> {
+        let mut arn = Arn {
+            partition: region.partition(),
+            region: Component::Value(region),
+            ..Arn::default()
+        };
+        arn.set_service(SERVICE)?;
+        arn.set_account(account)?;
+        arn.set_resource_id(&format!("repository/{name}"))?;
+        arn.try_into()
+    }
+
+    /// The repository name, which may contain slashes (`team/app`).
+    pub fn repository_name(&self) -> &str {
+        &resource_of(&self.0)["repository/".len()..]
+    }
+
+    pub fn registry_uri(&self) -> String {
+        let (Component::Value(region), Component::Value(account)) =
+            (self.0.region, self.0.get(ArnField::Account))
+        else {
+            unreachable!("checked in TryFrom<Arn>")
+        };
+        format!(
+            "{account}.dkr.ecr.{}.{}/{}",
+            region.as_ref(),
+            self.0.partition.dns_suffix(),
+            self.repository_name()
+        )
+    }
+
+    /// Parses a registry URI such as
+    /// `123456789012.dkr.ecr.eu-west-1.amazonaws.com/team/app`. An optional
+    /// `https://` scheme and any `:tag` or `@digest` are ignored.
+    pub fn from_registry_uri(uri: &str) -> Result<Self, ValidationError> {
+        let uri = uri.strip_prefix("https://").unwrap_or(uri);
+        let invalid = || {
+            ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{uri}' is not an ECR registry URI"),
+            )
+        };
+        let (host, name) = uri.split_once('/').ok_or_else(invalid)?;
+        let name = name.split_once('@').map_or(name, |(name, _)| name);
+        let name = match name.rsplit_once(':') {
+            Some((name, tag)) if !tag.contains('/') => name,
+            _ => name,
+        };
+
+        let mut labels = host.splitn(5, '.');
+        let (Some(account), Some("dkr"), Some("ecr"), Some(region), Some(suffix)) = (
+            labels.next(),
+            labels.next(),
+            labels.next(),
+            labels.next(),
+            labels.next(),
+        ) else {
+            return Err(invalid());
+        };
+        let region: Region = region
+            .parse()
+            .map_err(|_| ValidationError::invalid(ArnField::Region, region))?;
+        if suffix != region.partition().dns_suffix() {
+            return Err(ValidationError::invalid(
+                ArnField::Partition,
+                format!("'{suffix}' is not the DNS suffix for {}", region.as_ref()),
+            ));
+        }
+        Self::new(region, account, name)
+    }
+}
+
+impl TryFrom<Arn> for EcrRepositoryArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        check_name(resource(&arn, SERVICE, "repository/")?)?;
+        if !matches!(arn.region, Component::Value(_)) {
+            return Err(ValidationError::invalid(ArnField::Region, "missing"));
+        }
+        match arn.get(ArnField::Account) {
+            Component::Value(account) if is_account_id(account) => Ok(EcrRepositoryArn(arn)),
+            _ => Err(ValidationError::invalid(
+                ArnField::Account,
+                "expected a 12-digit account ID",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_uri_roundtrip() {
+        let repo: EcrRepositoryArn = "arn:aws:ecr:eu-west-1:123456789012:repository/team/app"
+            .parse()
+            .unwrap();
+        assert_eq!(repo.repository_name(), "team/app");
+        let uri = "123456789012.dkr.ecr.eu-west-1.amazonaws.com/team/app";
+        assert_eq!(repo.registry_uri(), uri);
+        assert_eq!(EcrRepositoryArn::from_registry_uri(uri).unwrap(), repo);
+        for variant in [
+            format!("https://{uri}"),
+            format!("{uri}:v1.2"),
+            format!("{uri}@sha256:abc123"),
+        ] {
+            assert_eq!(EcrRepositoryArn::from_registry_uri(&variant).unwrap(), repo);
+        }
+    }
+
+    #[test]
+    fn test_partition_aware() {
+        let repo = EcrRepositoryArn::new(Region::CnNorth1, "123456789012", "app").unwrap();
+        assert_eq!(
+            repo.to_string(),
+            "arn:aws-cn:ecr:cn-north-1:123456789012:repository/app"
+        );
+        assert_eq!(
+            repo.registry_uri(),
+            "123456789012.dkr.ecr.cn-north-1.amazonaws.com.cn/app"
+        );
+        assert_eq!(
+            EcrRepositoryArn::from_registry_uri(&repo.registry_uri()).unwrap(),
+            repo
+        );
+        assert!(matches!(
+            EcrRepositoryArn::from_registry_uri(
+                "123456789012.dkr.ecr.cn-north-1.amazonaws.com/app"
+            ),
+            Err(ValidationError::Invalid {
+                field: ArnField::Partition,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for uri in [
+            "123456789012.dkr.ecr.eu-west-1.amazonaws.com",
+            "docker.io/library/nginx",
+            "123456789012.dkr.ecr.mars-1.amazonaws.com/app",
+            "123.dkr.ecr.eu-west-1.amazonaws.com/app",
+            "123456789012.dkr.ecr.eu-west-1.amazonaws.com/App",
+        ] {
+            assert!(EcrRepositoryArn::from_registry_uri(uri).is_err(), "{uri}");
+        }
+        assert!(
+            "arn:aws:ecr:eu-west-1::repository/app"
+                .parse::<EcrRepositoryArn>()
+                .is_err()
+        );
+    }
+}
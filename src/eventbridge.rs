@@ -0,0 +1,151 @@
+//! EventBridge event bus and rule ARNs.
+
+use crate::{
+    Arn, ArnField,
+    typed::{resource, resource_of},
+    validate::ValidationError,
+};
+
+const SERVICE: &str = "events";
+const DEFAULT_BUS: &str = "default";
+
+/// `arn:aws:events:{region}:{account}:event-bus/{name}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct EventBusArn(Arn);
+
+typed_arn!(EventBusArn);
+
+impl EventBusArn {
+    /// The bus name, which for partner buses contains slashes, e.g.
+    /// `aws.partner/example.com/123/source`.
+    pub fn name(&self) -> &str {
+        &resource_of(&self.0)["event-bus/".len()..]
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.name() == DEFAULT_BUS
+    }
+}
+
+impl TryFrom<Arn> for EventBusArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        if resource(&arn, SERVICE, "event-bus/")?.is_empty() {
+            return Err(ValidationError::invalid(
+                ArnField::Resource,
+                "empty bus name",
+            ));
+        }
+        Ok(EventBusArn(arn))
+    }
+}
+
+/// `arn:aws:events:{region}:{account}:rule/[{bus-name}/]{rule-name}`, where
+/// the bus is omitted for rules on the default bus.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct EventRuleArn(Arn);
+
+typed_arn!(EventRuleArn);
+
+impl EventRuleArn {
+    fn parts(&self) -> (Option<&str>, &str) {
+        let rest = &resource_of(&self.0)["rule/".len()..];
+        // Rule names can't contain '/', but partner bus names do.
+        match rest.rsplit_once('/') {
+            Some((bus, rule)) => (Some(bus), rule),
+            None => (None, rest),
+        }
+    }
+
+    /// The name of the bus the rule is on, `default` if the ARN omits it.
+    pub fn bus_name(&self) -> &str {
+        self.parts().0.unwrap_or(DEFAULT_BUS)
+    }
+
+    pub fn rule_name(&self) -> &str {
+        self.parts().1
+    }
+
+    pub fn is_default_bus(&self) -> bool {
+        self.bus_name() == DEFAULT_BUS
+    }
+}
+
+impl TryFrom<Arn> for EventRuleArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        let rest = resource(&arn, SERVICE, "rule/")?;
+        if rest.is_empty() || rest.starts_with('/') || rest.ends_with('/') {
+            return Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("'rule/{rest}' is not rule/[bus/]name"),
+            ));
+        }
+        Ok(EventRuleArn(arn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREFIX: &str = "arn:aws:events:us-east-1:123456789012";
+
+    #[test]
+    fn test_event_bus() {
+        let bus: EventBusArn = format!("{PREFIX}:event-bus/orders").parse().unwrap();
+        assert_eq!(bus.name(), "orders");
+        assert!(!bus.is_default());
+        assert_eq!(bus.to_string(), format!("{PREFIX}:event-bus/orders"));
+
+        let partner: EventBusArn = format!("{PREFIX}:event-bus/aws.partner/example.com/1/src")
+            .parse()
+            .unwrap();
+        assert_eq!(partner.name(), "aws.partner/example.com/1/src");
+
+        assert!(
+            format!("{PREFIX}:event-bus/")
+                .parse::<EventBusArn>()
+                .is_err()
+        );
+        assert!(format!("{PREFIX}:rule/r").parse::<EventBusArn>().is_err());
+        assert!(matches!(
+            "arn:aws:sqs:us-east-1:123456789012:event-bus/orders".parse::<EventBusArn>(),
+            Err(ValidationError::Invalid {
+                field: ArnField::Service,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule: EventRuleArn = format!("{PREFIX}:rule/nightly").parse().unwrap();
+        assert_eq!(rule.bus_name(), "default");
+        assert_eq!(rule.rule_name(), "nightly");
+        assert!(rule.is_default_bus());
+
+        let rule: EventRuleArn = format!("{PREFIX}:rule/orders/on-created").parse().unwrap();
+        assert_eq!(rule.bus_name(), "orders");
+        assert_eq!(rule.rule_name(), "on-created");
+
+        let rule: EventRuleArn = format!("{PREFIX}:rule/aws.partner/example.com/1/src/r")
+            .parse()
+            .unwrap();
+        assert_eq!(rule.bus_name(), "aws.partner/example.com/1/src");
+        assert_eq!(rule.rule_name(), "r");
+
+        for bad in ["rule/", "rule/orders/", "event-bus/orders"] {
+            assert!(
+                format!("{PREFIX}:{bad}").parse::<EventRuleArn>().is_err(),
+                "{bad}"
+            );
+        }
+        assert!(matches!(
+            PREFIX.parse::<EventRuleArn>(),
+            Err(ValidationError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,263 @@
+//! C ABI over the parser and [`ArnPattern`] matching; see `include/arn.h`.
+//!
+//! Build a shared library with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! Parsed ARNs are opaque heap pointers owned by the caller and released with
+//! [`arn_free`]. Strings are copied out `snprintf`-style: accessors write at
+//! most `len` bytes including the NUL terminator and return the full length,
+//! so a return value `>= len` means the output was truncated. A null `arn`
+//! returns 0 and sets [`arn_last_error`].
+
+use crate::{Arn, ArnField, ArnPattern, Component};
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString, c_char, c_int},
+    ptr,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: impl ToString) {
+    let message = CString::new(message.to_string()).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+unsafe fn input<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        set_last_error("null string");
+        return None;
+    }
+    match unsafe { CStr::from_ptr(s) }.to_str() {
+        Ok(s) => Some(s),
+        Err(_) => {
+            set_last_error("string is not valid UTF-8");
+            None
+        }
+    }
+}
+
+unsafe fn copy_out(value: &str, buf: *mut c_char, len: usize) -> usize {
+    if !buf.is_null() && len > 0 {
+        let n = value.len().min(len - 1);
+        unsafe {
+            ptr::copy_nonoverlapping(value.as_ptr().cast(), buf, n);
+            *buf.add(n) = 0;
+        }
+    }
+    value.len()
+}
+
+unsafe fn component(arn: *const Arn, field: ArnField, buf: *mut c_char, len: usize) -> usize {
+    let Some(arn) = (unsafe { arn.as_ref() }) else {
+        set_last_error("null ARN");
+        return 0;
+    };
+    let value = match arn.get(field) {
+        Component::None => "",
+        Component::Any => "*",
+        Component::Value(v) => v,
+    };
+    unsafe { copy_out(value, buf, len) }
+}
+
+/// Parses `input`, returning null on failure (see [`arn_last_error`]).
+///
+/// # Safety
+///
+/// `input` must be null or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_parse(input: *const c_char) -> *mut Arn {
+    let Some(input) = (unsafe { self::input(input) }) else {
+        return ptr::null_mut();
+    };
+    match input.parse::<Arn>() {
+        Ok(arn) => Box::into_raw(Box::new(arn)),
+        Err(e) => {
+            set_last_error(e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases an ARN returned by [`arn_parse`]. Null is ignored.
+///
+/// # Safety
+///
+/// `arn` must be null or a pointer from [`arn_parse`] not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_free(arn: *mut Arn) {
+    if !arn.is_null() {
+        drop(unsafe { Box::from_raw(arn) });
+    }
+}
+
+/// The message for the last failure on this thread, or null. Valid until the
+/// next call into this library on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn arn_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+/// Copies the canonical string form of `arn` into `buf`.
+///
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_to_string(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    let Some(arn) = (unsafe { arn.as_ref() }) else {
+        set_last_error("null ARN");
+        return 0;
+    };
+    unsafe { copy_out(&arn.to_string(), buf, len) }
+}
+
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_partition(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    unsafe { component(arn, ArnField::Partition, buf, len) }
+}
+
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_service(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    unsafe { component(arn, ArnField::Service, buf, len) }
+}
+
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_region(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    unsafe { component(arn, ArnField::Region, buf, len) }
+}
+
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_account(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    unsafe { component(arn, ArnField::Account, buf, len) }
+}
+
+/// # Safety
+///
+/// `arn` must be null or valid; `buf` must be null or valid for `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_resource(arn: *const Arn, buf: *mut c_char, len: usize) -> usize {
+    unsafe { component(arn, ArnField::Resource, buf, len) }
+}
+
+/// Returns 1 if `arn` matches the IAM-style `pattern`, 0 if not, and -1 if
+/// the pattern is invalid or `arn` is null.
+///
+/// # Safety
+///
+/// `pattern` must be null or a valid NUL-terminated string; `arn` must be null
+/// or valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn arn_matches(pattern: *const c_char, arn: *const Arn) -> c_int {
+    let Some(arn) = (unsafe { arn.as_ref() }) else {
+        set_last_error("null ARN");
+        return -1;
+    };
+    let Some(pattern) = (unsafe { input(pattern) }) else {
+        return -1;
+    };
+    match pattern.parse::<ArnPattern>() {
+        Ok(pattern) => c_int::from(pattern.matches(arn)),
+        Err(e) => {
+            set_last_error(e);
+            -1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(f: impl Fn(*mut c_char, usize) -> usize) -> String {
+        let mut buf = [0 as c_char; 128];
+        let n = f(buf.as_mut_ptr(), buf.len());
+        assert!(n < buf.len());
+        unsafe { CStr::from_ptr(buf.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_parse_and_accessors() {
+        let input = c"arn:aws:lambda:us-east-1:123456789012:function:f";
+        unsafe {
+            let arn = arn_parse(input.as_ptr());
+            assert!(!arn.is_null());
+            assert_eq!(read(|b, l| arn_partition(arn, b, l)), "aws");
+            assert_eq!(read(|b, l| arn_service(arn, b, l)), "lambda");
+            assert_eq!(read(|b, l| arn_region(arn, b, l)), "us-east-1");
+            assert_eq!(read(|b, l| arn_account(arn, b, l)), "123456789012");
+            assert_eq!(read(|b, l| arn_resource(arn, b, l)), "function:f");
+            assert_eq!(
+                read(|b, l| arn_to_string(arn, b, l)),
+                input.to_str().unwrap()
+            );
+            arn_free(arn);
+        }
+    }
+
+    #[test]
+    fn test_truncation() {
+        unsafe {
+            let arn = arn_parse(c"arn:aws:s3:::bucket".as_ptr());
+            let mut buf = [0x7f as c_char; 4];
+            assert_eq!(arn_resource(arn, buf.as_mut_ptr(), buf.len()), 6);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_bytes(), b"buc");
+            assert_eq!(arn_resource(arn, ptr::null_mut(), 0), 6);
+            arn_free(arn);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        unsafe {
+            assert!(arn_parse(c"arn:aws:s3".as_ptr()).is_null());
+            let error = CStr::from_ptr(arn_last_error()).to_str().unwrap();
+            assert!(error.contains("got 3"), "{error}");
+            assert!(arn_parse(ptr::null()).is_null());
+            arn_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_null_arn() {
+        unsafe {
+            for accessor in [arn_to_string, arn_service, arn_resource] {
+                // Leave a different error behind first.
+                arn_parse(c"arn:aws".as_ptr());
+                let mut buf = [0x7f as c_char; 8];
+                assert_eq!(accessor(ptr::null(), buf.as_mut_ptr(), buf.len()), 0);
+                assert_eq!(CStr::from_ptr(arn_last_error()).to_bytes(), b"null ARN");
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        unsafe {
+            let arn = arn_parse(c"arn:aws:s3:::bucket/key".as_ptr());
+            assert_eq!(arn_matches(c"arn:aws:s3:::bucket/*".as_ptr(), arn), 1);
+            assert_eq!(arn_matches(c"arn:aws:s3:::other/*".as_ptr(), arn), 0);
+            assert_eq!(arn_matches(c"arn:aws".as_ptr(), arn), -1);
+            assert_eq!(arn_matches(c"arn:*:*:*:*:*".as_ptr(), ptr::null()), -1);
+            arn_free(arn);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{Arn, ArnParseError, Component, Partition, Region};
+use arrayvec::ArrayString;
+use std::{
+    cell::Cell,
+    collections::hash_map::RandomState,
+    fmt::Write,
+    hash::{BuildHasher, Hasher},
+};
+use strum::VariantArray;
+
+const SERVICES: &[&str] = &["s3", "lambda", "iam", "sqs", "sns", "dynamodb", "ec2"];
+
+const WORDS: &[&str] = &[
+    "alpha", "billing", "data", "events", "ingest", "logs", "orders", "payments", "reports",
+    "staging", "users", "worker",
+];
+
+thread_local! {
+    static STATE: Cell<u64> = Cell::new(RandomState::new().build_hasher().finish() | 1);
+}
+
+// xorshift64*, seeded per thread; good enough for test data, not for anything else.
+fn next_u64() -> u64 {
+    STATE.with(|state| {
+        let mut x = state.get();
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        state.set(x);
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    })
+}
+
+fn pick<T: Copy>(items: &[T]) -> T {
+    items[(next_u64() % items.len() as u64) as usize]
+}
+
+/// A random 12-digit account ID.
+pub fn account_id() -> ArrayString<12> {
+    let mut account = ArrayString::new();
+    for _ in 0..12 {
+        account.push(char::from(b'0' + (next_u64() % 10) as u8));
+    }
+    account
+}
+
+/// A random known region in the `aws` partition.
+pub fn region() -> Region {
+    loop {
+        let region = pick(Region::VARIANTS);
+        if region.partition() == Partition::Aws {
+            return region;
+        }
+    }
+}
+
+/// A random lowercase name such as `orders-data-4f2a`.
+pub fn resource_name() -> String {
+    format!("{}-{}-{:04x}", pick(WORDS), pick(WORDS), next_u64() as u16)
+}
+
+fn hex(len: usize) -> String {
+    let mut s = String::with_capacity(len);
+    while s.len() < len {
+        write!(s, "{:x}", next_u64() % 16).unwrap();
+    }
+    s
+}
+
+impl Arn {
+    /// A realistic random ARN for one of a handful of common services.
+    pub fn fake() -> Arn {
+        Self::fake_for_service(pick(SERVICES)).unwrap()
+    }
+
+    /// A realistic random ARN for `service`, shaped the way AWS shapes it,
+    /// e.g. no region or account for S3 and no region for IAM.
+    pub fn fake_for_service(service: &str) -> Result<Arn, ArnParseError> {
+        let name = resource_name();
+        let (region, account, resource) = match service {
+            "s3" => (Component::None, Component::None, name),
+            "iam" => (
+                Component::None,
+                Component::Value(account_id()),
+                format!("role/{name}"),
+            ),
+            "lambda" => (
+                Component::Value(region()),
+                Component::Value(account_id()),
+                format!("function:{name}"),
+            ),
+            "dynamodb" => (
+                Component::Value(region()),
+                Component::Value(account_id()),
+                format!("table/{name}"),
+            ),
+            "ec2" => (
+                Component::Value(region()),
+                Component::Value(account_id()),
+                format!("instance/i-{}", hex(17)),
+            ),
+            _ => (
+                Component::Value(region()),
+                Component::Value(account_id()),
+                name,
+            ),
+        };
+
+        Ok(Arn {
+            partition: Partition::Aws,
+            service: Component::Value(
+                ArrayString::from(service).map_err(|_| ArnParseError::ServiceTooLong)?,
+            ),
+            region,
+            account,
+            resource_id: Component::Value(
+                ArrayString::from(&resource).map_err(|_| ArnParseError::ResourceIdTooLong)?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_roundtrips() {
+        for _ in 0..100 {
+            let arn = Arn::fake();
+            assert_eq!(arn.to_string().parse::<Arn>().unwrap(), arn);
+        }
+    }
+
+    #[test]
+    fn test_fake_for_service_shapes() {
+        let s3 = Arn::fake_for_service("s3").unwrap();
+        assert_eq!(s3.region, Component::None);
+        assert_eq!(s3.account, Component::None);
+
+        let iam = Arn::fake_for_service("iam").unwrap();
+        assert_eq!(iam.region, Component::None);
+        assert!(iam.to_string().contains(":role/"));
+
+        let ec2 = Arn::fake_for_service("ec2").unwrap();
+        assert!(matches!(ec2.region, Component::Value(_)));
+        assert!(ec2.to_string().contains(":instance/i-"));
+    }
+
+    #[test]
+    fn test_fake_for_service_too_long() {
+        let result = Arn::fake_for_service(&"a".repeat(33));
+        assert!(matches!(result, Err(ArnParseError::ServiceTooLong)));
+    }
+
+    #[test]
+    fn test_account_id() {
+        let account = account_id();
+        assert_eq!(account.len(), 12);
+        assert!(account.chars().all(|c| c.is_ascii_digit()));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::Region;
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum Geography {
+    NorthAmerica,
+    SouthAmerica,
+    Europe,
+    MiddleEast,
+    Africa,
+    AsiaPacific,
+}
+
+impl Region {
+    pub fn geography(&self) -> Geography {
+        use Geography::*;
+        use Region::*;
+
+        match self {
+            UsEast1 | UsEast2 | UsWest1 | UsWest2 | CaCentral1 | CaWest1 | MxCentral1
+            | UsGovEast1 | UsGovWest1 | UsIsoEast1 | UsIsoWest1 | UsIsobEast1 | UsIsobWest1
+            | UsIsofEast1 | UsIsofSouth1 => NorthAmerica,
+            SaEast1 => SouthAmerica,
+            EuCentral1 | EuCentral2 | EuWest1 | EuWest2 | EuWest3 | EuSouth1 | EuSouth2
+            | EuNorth1 | EuNorth2 | EuIsoeWest1 | EuscDeEast1 => Europe,
+            IlCentral1 | MeSouth1 | MeCentral1 => MiddleEast,
+            AfSouth1 => Africa,
+            ApEast1 | ApEast2 | ApSouth1 | ApSouth2 | ApSoutheast1 | ApSoutheast2
+            | ApSoutheast3 | ApSoutheast4 | ApSoutheast5 | ApSoutheast6 | ApSoutheast7
+            | ApNortheast1 | ApNortheast2 | ApNortheast3 | CnNorth1 | CnNorthwest1 => AsiaPacific,
+        }
+    }
+
+    pub fn same_geography(&self, other: &Region) -> bool {
+        self.geography() == other.geography()
+    }
+
+    // Approximate (latitude, longitude) of the metro area each region serves.
+    fn coordinates(&self) -> (f64, f64) {
+        use Region::*;
+
+        match self {
+            UsEast1 => (38.9, -77.4),
+            UsEast2 => (40.0, -83.0),
+            UsWest1 => (37.4, -121.9),
+            UsWest2 => (45.8, -119.7),
+            AfSouth1 => (-33.9, 18.4),
+            ApEast1 => (22.3, 114.2),
+            ApEast2 => (25.0, 121.5),
+            ApSouth1 => (19.1, 72.9),
+            ApSouth2 => (17.4, 78.5),
+            ApSoutheast1 => (1.4, 103.8),
+            ApSoutheast2 => (-33.9, 151.2),
+            ApSoutheast3 => (-6.2, 106.8),
+            ApSoutheast4 => (-37.8, 145.0),
+            ApSoutheast5 => (3.1, 101.7),
+            ApSoutheast6 => (-36.8, 174.8),
+            ApSoutheast7 => (13.8, 100.5),
+            ApNortheast1 => (35.7, 139.7),
+            ApNortheast2 => (37.6, 127.0),
+            ApNortheast3 => (34.7, 135.5),
+            CaCentral1 => (45.5, -73.6),
+            CaWest1 => (51.0, -114.1),
+            EuCentral1 => (50.1, 8.7),
+            EuCentral2 => (47.4, 8.5),
+            EuWest1 => (53.3, -6.3),
+            EuWest2 => (51.5, -0.1),
+            EuWest3 => (48.9, 2.4),
+            EuSouth1 => (45.5, 9.2),
+            EuSouth2 => (41.6, -0.9),
+            EuNorth1 => (59.3, 18.1),
+            // Not launched yet; assumed to sit alongside eu-north-1.
+            EuNorth2 => (59.3, 18.1),
+            IlCentral1 => (32.1, 34.8),
+            MxCentral1 => (20.6, -100.4),
+            MeSouth1 => (26.1, 50.6),
+            MeCentral1 => (24.5, 54.4),
+            SaEast1 => (-23.5, -46.6),
+            CnNorth1 => (39.9, 116.4),
+            CnNorthwest1 => (37.5, 105.2),
+            UsGovEast1 | UsIsobEast1 => (40.0, -83.0),
+            UsGovWest1 => (45.8, -119.7),
+            EuscDeEast1 => (52.4, 13.1),
+            // Locations aren't published; approximated by the nearest commercial region.
+            UsIsoEast1 | UsIsofEast1 => (38.9, -77.4),
+            UsIsoWest1 | UsIsobWest1 => (45.8, -119.7),
+            UsIsofSouth1 => (40.0, -83.0),
+            EuIsoeWest1 => (51.5, -0.1),
+        }
+    }
+
+    /// Great-circle distance in kilometres between the areas two regions
+    /// serve, a rough proxy for network latency.
+    pub fn distance_km(&self, other: &Region) -> f64 {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
+
+        let (lat1, lon1) = self.coordinates();
+        let (lat2, lon2) = other.coordinates();
+        let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (lon2 - lon1).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+
+    /// The closest of `candidates`, or `None` if there are none. `self` is
+    /// returned if it appears in `candidates`.
+    pub fn nearest(&self, candidates: &[Region]) -> Option<Region> {
+        candidates
+            .iter()
+            .copied()
+            .min_by(|a, b| self.distance_km(a).total_cmp(&self.distance_km(b)))
+    }
+
+    /// Sorts `regions` from closest to furthest.
+    pub fn sort_by_proximity(&self, regions: &mut [Region]) {
+        regions.sort_by(|a, b| self.distance_km(a).total_cmp(&self.distance_km(b)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geography() {
+        assert_eq!(Region::UsWest2.geography(), Geography::NorthAmerica);
+        assert_eq!(Region::IlCentral1.geography(), Geography::MiddleEast);
+        assert!(Region::EuWest1.same_geography(&Region::EuCentral1));
+        assert!(!Region::EuWest1.same_geography(&Region::UsEast1));
+    }
+
+    #[test]
+    fn test_distance_km() {
+        assert_eq!(Region::UsEast1.distance_km(&Region::UsEast1), 0.0);
+        let d = Region::EuWest2.distance_km(&Region::EuCentral1);
+        assert!((500.0..800.0).contains(&d), "{d}");
+        assert_eq!(
+            Region::UsEast1.distance_km(&Region::ApSoutheast2),
+            Region::ApSoutheast2.distance_km(&Region::UsEast1)
+        );
+    }
+
+    #[test]
+    fn test_nearest() {
+        let candidates = [Region::UsWest2, Region::EuCentral1, Region::ApNortheast1];
+        assert_eq!(
+            Region::EuWest1.nearest(&candidates),
+            Some(Region::EuCentral1)
+        );
+        assert_eq!(
+            Region::ApNortheast2.nearest(&candidates),
+            Some(Region::ApNortheast1)
+        );
+        assert_eq!(Region::UsWest1.nearest(&candidates), Some(Region::UsWest2));
+        assert_eq!(Region::UsEast1.nearest(&[]), None);
+    }
+
+    #[test]
+    fn test_sort_by_proximity() {
+        let mut regions = [Region::ApSoutheast2, Region::UsEast2, Region::EuWest1];
+        Region::UsEast1.sort_by_proximity(&mut regions);
+        assert_eq!(
+            regions,
+            [Region::UsEast2, Region::EuWest1, Region::ApSoutheast2]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Which services' ARNs carry a region and an account, independent of the
+//! `availability` endpoint table.
+
+use crate::{Arn, Component};
+
+/// Services whose ARNs have an empty region, sorted.
+const GLOBAL_SERVICES: &[&str] = &[
+    "cloudfront",
+    "globalaccelerator",
+    "iam",
+    "organizations",
+    "route53",
+    "shield",
+    "sts",
+    "waf",
+];
+
+/// Services with at least some resources (S3 buckets, Route 53 hosted zones)
+/// whose ARNs have an empty account, sorted.
+const ACCOUNTLESS_SERVICES: &[&str] = &["route53", "s3"];
+
+/// Whether `service` is global, so its ARNs have an empty region.
+pub fn is_global_service(service: &str) -> bool {
+    GLOBAL_SERVICES.binary_search(&service).is_ok()
+}
+
+/// Whether every ARN for `service` has a region. `false` also for S3, whose
+/// bucket ARNs have none but whose access point ARNs do.
+pub fn requires_region(service: &str) -> bool {
+    !is_global_service(service) && service != "s3"
+}
+
+/// Whether every ARN for `service` has an account.
+pub fn requires_account(service: &str) -> bool {
+    ACCOUNTLESS_SERVICES.binary_search(&service).is_err()
+}
+
+impl Arn {
+    /// Whether this ARN's service is global, and so its ARNs carry no region.
+    pub fn is_global(&self) -> bool {
+        matches!(self.service, Component::Value(s) if is_global_service(&s))
+    }
+
+    /// `false` if a known service's ARN is missing a region or account its
+    /// ARNs always have, or has a region though the service is global, e.g.
+    /// an EC2 ARN without a region. Wildcards and empty services pass.
+    pub fn has_expected_shape(&self) -> bool {
+        let Component::Value(service) = &self.service else {
+            return true;
+        };
+        let region_ok = match self.region {
+            Component::None => !requires_region(service),
+            Component::Value(_) => !is_global_service(service),
+            Component::Any => true,
+        };
+        region_ok && (self.account != Component::None || !requires_account(service))
+    }
+
+    /// Equality that ignores the region of global services such as IAM and
+    /// Route 53, so `arn:aws:iam:us-east-1:...` (as some scanners report it)
+    /// equals the canonical `arn:aws:iam::...`. Otherwise the same as `==`.
+    pub fn eq_semantic(&self, other: &Arn) -> bool {
+        if self.is_global() && self.service == other.service {
+            Arn {
+                region: other.region,
+                ..*self
+            } == *other
+        } else {
+            self == other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arn(s: &str) -> Arn {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_global_services_sorted() {
+        assert!(GLOBAL_SERVICES.is_sorted());
+        assert!(ACCOUNTLESS_SERVICES.is_sorted());
+    }
+
+    #[test]
+    fn test_is_global() {
+        assert!(arn("arn:aws:iam::123456789012:role/r").is_global());
+        assert!(arn("arn:aws:route53:::hostedzone/Z1").is_global());
+        assert!(!arn("arn:aws:s3:::bucket").is_global());
+        assert!(!arn("arn:aws:ec2:us-east-1:123456789012:instance/i-1").is_global());
+        assert!(!Arn::ANY.is_global());
+
+        assert!(arn("arn:aws:iam::123456789012:role/r").has_expected_shape());
+        assert!(arn("arn:aws:s3:::bucket").has_expected_shape());
+        assert!(!arn("arn:aws:iam:us-east-1:123456789012:role/r").has_expected_shape());
+        assert!(!arn("arn:aws:ec2::123456789012:instance/i-1").has_expected_shape());
+        assert!(!arn("arn:aws:sqs:us-east-1::queue").has_expected_shape());
+        assert!(Arn::ANY.has_expected_shape());
+
+        assert!(requires_region("ec2"));
+        assert!(!requires_region("iam"));
+        assert!(!requires_region("s3"));
+        assert!(requires_account("iam"));
+        assert!(!requires_account("s3"));
+    }
+
+    #[test]
+    fn test_eq_semantic() {
+        let canonical = arn("arn:aws:iam::123456789012:role/r");
+        let scanned = arn("arn:aws:iam:us-east-1:123456789012:role/r");
+        assert_ne!(canonical, scanned);
+        assert!(canonical.eq_semantic(&scanned));
+        assert!(scanned.eq_semantic(&canonical));
+        assert!(!canonical.eq_semantic(&arn("arn:aws:iam:us-east-1:123456789012:role/other")));
+        assert!(!canonical.eq_semantic(&arn("arn:aws-cn:iam::123456789012:role/r")));
+
+        let east = arn("arn:aws:sqs:us-east-1:123456789012:queue");
+        assert!(east.eq_semantic(&east));
+        assert!(!east.eq_semantic(&arn("arn:aws:sqs:us-west-2:123456789012:queue")));
+    }
+}
@@ -0,0 +1,117 @@
+//! Grouping and counting ARN collections, e.g. for cost and ownership
+//! reports.
+
+use crate::{Arn, Component, Region};
+use arrayvec::ArrayString;
+use std::{collections::HashMap, hash::Hash};
+
+fn group_by<K: Hash + Eq>(
+    arns: impl IntoIterator<Item = Arn>,
+    key: impl Fn(&Arn) -> K,
+) -> HashMap<K, Vec<Arn>> {
+    let mut groups: HashMap<K, Vec<Arn>> = HashMap::new();
+    for arn in arns {
+        groups.entry(key(&arn)).or_default().push(arn);
+    }
+    groups
+}
+
+/// ARNs keyed by service, in their original order within each group.
+pub fn group_by_service(
+    arns: impl IntoIterator<Item = Arn>,
+) -> HashMap<Component<ArrayString<32>>, Vec<Arn>> {
+    group_by(arns, |arn| arn.service)
+}
+
+/// ARNs keyed by region; global resources are under [`Component::None`].
+pub fn group_by_region(
+    arns: impl IntoIterator<Item = Arn>,
+) -> HashMap<Component<Region>, Vec<Arn>> {
+    group_by(arns, |arn| arn.region)
+}
+
+/// ARNs keyed by account.
+pub fn group_by_account(
+    arns: impl IntoIterator<Item = Arn>,
+) -> HashMap<Component<ArrayString<12>>, Vec<Arn>> {
+    group_by(arns, |arn| arn.account)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct SummaryKey {
+    pub service: Component<ArrayString<32>>,
+    pub region: Component<Region>,
+    pub account: Component<ArrayString<12>>,
+}
+
+impl From<&Arn> for SummaryKey {
+    fn from(arn: &Arn) -> Self {
+        SummaryKey {
+            service: arn.service,
+            region: arn.region,
+            account: arn.account,
+        }
+    }
+}
+
+/// The number of resources per service, region, and account.
+pub fn summarize(arns: impl IntoIterator<Item = Arn>) -> HashMap<SummaryKey, usize> {
+    let mut counts = HashMap::new();
+    for arn in arns {
+        *counts.entry(SummaryKey::from(&arn)).or_default() += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arns() -> Vec<Arn> {
+        [
+            "arn:aws:s3:::a",
+            "arn:aws:s3:::b",
+            "arn:aws:sqs:us-east-1:123456789012:q1",
+            "arn:aws:sqs:us-east-1:123456789012:q2",
+            "arn:aws:sqs:eu-west-1:123456789012:q3",
+            "arn:aws:iam::210987654321:role/r",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect()
+    }
+
+    fn value<const N: usize>(s: &str) -> Component<ArrayString<N>> {
+        Component::Value(ArrayString::from(s).unwrap())
+    }
+
+    #[test]
+    fn test_group_by() {
+        let by_service = group_by_service(arns());
+        assert_eq!(by_service.len(), 3);
+        assert_eq!(by_service[&value("sqs")].len(), 3);
+        assert_eq!(by_service[&value("s3")][1].to_string(), "arn:aws:s3:::b");
+
+        let by_region = group_by_region(arns());
+        assert_eq!(by_region[&Component::None].len(), 3);
+        assert_eq!(by_region[&Component::Value(Region::UsEast1)].len(), 2);
+
+        let by_account = group_by_account(arns());
+        assert_eq!(by_account[&value("123456789012")].len(), 3);
+        assert_eq!(by_account[&Component::None].len(), 2);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let summary = summarize(arns());
+        assert_eq!(summary.len(), 4);
+        assert_eq!(summary.values().sum::<usize>(), 6);
+        let key = SummaryKey {
+            service: value("sqs"),
+            region: Component::Value(Region::UsEast1),
+            account: value("123456789012"),
+        };
+        assert_eq!(summary[&key], 2);
+        assert_eq!(summary[&SummaryKey::from(&arns()[0])], 2);
+    }
+}
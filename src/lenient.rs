@@ -0,0 +1,126 @@
+use crate::{Arn, ArnField, ArnParseError};
+use std::fmt;
+
+/// A non-fatal problem found by [`Arn::parse_lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// The component was cut down to `max` bytes from `len`.
+    Truncated {
+        field: ArnField,
+        len: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::Truncated { field, len, max } => {
+                write!(f, "{} truncated from {len} to {max} bytes", field.as_ref())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientArn {
+    pub arn: Arn,
+    pub warnings: Vec<ParseWarning>,
+}
+
+// Longest prefix of `value` that fits in `max` bytes without splitting a char.
+fn truncate<'a>(
+    value: &'a str,
+    field: ArnField,
+    max: usize,
+    warnings: &mut Vec<ParseWarning>,
+) -> &'a str {
+    if value.len() <= max {
+        return value;
+    }
+    let end = (0..=max)
+        .rev()
+        .find(|i| value.is_char_boundary(*i))
+        .unwrap();
+    warnings.push(ParseWarning::Truncated {
+        field,
+        len: value.len(),
+        max: end,
+    });
+    &value[..end]
+}
+
+impl Arn {
+    /// Like [`FromStr`](std::str::FromStr), but truncates an over-long
+    /// service, account, or resource ID to its capacity instead of failing,
+    /// recording a [`ParseWarning`] for each. A malformed ARN or an unknown
+    /// partition or region is still an error.
+    pub fn parse_lenient(value: &str) -> Result<LenientArn, ArnParseError> {
+        let mut parts = crate::split_parts(value)?;
+        let mut warnings = Vec::new();
+        parts[2] = truncate(parts[2], ArnField::Service, 32, &mut warnings);
+        parts[4] = truncate(parts[4], ArnField::Account, 12, &mut warnings);
+        parts[5] = truncate(parts[5], ArnField::Resource, 64, &mut warnings);
+
+        Ok(LenientArn {
+            arn: Self::from_parts(parts)?,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lenient() {
+        let input = format!("arn:aws:s3:::{}", "a".repeat(70));
+        let parsed = Arn::parse_lenient(&input).unwrap();
+        assert_eq!(
+            parsed.arn.to_string(),
+            format!("arn:aws:s3:::{}", "a".repeat(64))
+        );
+        assert_eq!(
+            parsed.warnings,
+            [ParseWarning::Truncated {
+                field: ArnField::Resource,
+                len: 70,
+                max: 64
+            }]
+        );
+        assert_eq!(
+            parsed.warnings[0].to_string(),
+            "resource truncated from 70 to 64 bytes"
+        );
+
+        let valid = Arn::parse_lenient("arn:aws:iam::123456789012:role/r").unwrap();
+        assert!(valid.warnings.is_empty());
+        assert_eq!(
+            valid.arn,
+            "arn:aws:iam::123456789012:role/r".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient_char_boundary() {
+        let input = format!("arn:aws:s3:::{}\u{fc}", "a".repeat(63));
+        let parsed = Arn::parse_lenient(&input).unwrap();
+        assert!(matches!(
+            parsed.warnings[..],
+            [ParseWarning::Truncated { max: 63, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_parse_lenient_errors() {
+        assert!(matches!(
+            Arn::parse_lenient("arn:aws:s3"),
+            Err(ArnParseError::InvalidFormat(3))
+        ));
+        assert!(matches!(
+            Arn::parse_lenient("arn:aws:s3:mars-1::bucket"),
+            Err(ArnParseError::InvalidRegion(_))
+        ));
+    }
+}
@@ -1,7 +1,52 @@
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use arrayvec::ArrayString;
-use std::{fmt, ops, str::FromStr};
+use std::{borrow::Cow, fmt, ops, str::FromStr};
 use thiserror::Error;
 
+#[macro_use]
+mod typed;
+
+pub mod acm;
+mod anonymize;
+#[cfg(feature = "availability")]
+pub mod availability;
+pub mod cloudtrail;
+mod compact;
+pub use compact::CompactArn;
+mod console;
+pub use console::ConsoleUrlError;
+mod context;
+pub use context::ArnContext;
+pub mod ecr;
+pub mod eventbridge;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod geography;
+pub use geography::Geography;
+pub mod global;
+pub mod group;
+mod lenient;
+pub use lenient::{LenientArn, ParseWarning};
+pub mod logs;
+mod pattern;
+pub use pattern::ArnPattern;
+mod qualifier;
+mod query;
+pub use query::ArnQuery;
+mod raw;
+pub use raw::RawArn;
+pub mod rds;
+mod region_data;
+pub use region_data::{Partition, Region};
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod resolve;
+pub mod route53;
+pub mod serde;
+mod static_arn;
+pub use static_arn::StaticArn;
+pub mod validate;
+
 #[derive(Error, Debug)]
 pub enum ArnParseError {
     #[error("Invalid ARN format: expected at least 6 parts separated by ':' but got {0}")]
@@ -14,19 +59,39 @@ pub enum ArnParseError {
     ResourceIdTooLong,
     #[error("Invalid region: {0}")]
     InvalidRegion(String),
+    #[error("Invalid partition: {0}")]
+    InvalidPartition(String),
+    #[error("ARN is not valid UTF-8")]
+    InvalidUtf8,
+    #[error(
+        "Service, account and resource too long for a compact ARN (max 91 characters combined)"
+    )]
+    CompactTooLong,
+    #[error("Invalid qualifier '{0}': the resource would not read it back as its qualifier")]
+    InvalidQualifier(String),
 }
 
-#[derive(Default, PartialEq, Eq, Hash, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(try_from = "String", into = "String")]
+#[derive(Default, PartialEq, Eq, Hash, Copy, Clone)]
 pub struct Arn {
+    pub partition: Partition,
     pub service: Component<ArrayString<32>>,
     pub region: Component<Region>,
     pub account: Component<ArrayString<12>>,
     pub resource_id: Component<ArrayString<64>>,
 }
 
+// Every component is fixed-capacity inline data, so ARNs are `Copy`; keep it
+// that way.
+const _: fn() = || {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<Arn>();
+    assert_copy::<CompactArn>();
+    assert_copy::<ArnBuilder>();
+};
+
 impl Arn {
     pub const ANY: Arn = Arn {
+        partition: Partition::Aws,
         service: Component::Any,
         region: Component::Any,
         account: Component::Any,
@@ -36,16 +101,178 @@ impl Arn {
     pub fn builder() -> ArnBuilder {
         ArnBuilder(Self::default())
     }
+
+    pub fn get(&self, field: ArnField) -> ComponentRef<'_> {
+        fn as_ref<V: Copy>(component: &Component<V>, f: impl Fn(&V) -> &str) -> ComponentRef<'_> {
+            match component {
+                Component::None => Component::None,
+                Component::Any => Component::Any,
+                Component::Value(v) => Component::Value(f(v)),
+            }
+        }
+
+        match field {
+            ArnField::Partition => Component::Value(self.partition.as_ref()),
+            ArnField::Service => as_ref(&self.service, |s| s.as_str()),
+            ArnField::Region => as_ref(&self.region, |r| r.as_ref()),
+            ArnField::Account => as_ref(&self.account, |a| a.as_str()),
+            ArnField::Resource => as_ref(&self.resource_id, |id| id.as_str()),
+        }
+    }
+
+    /// Every component in canonical order. Wildcards are yielded as `"*"` and
+    /// empty components as `None`.
+    pub fn components(&self) -> impl Iterator<Item = (ArnField, Option<&str>)> {
+        use strum::VariantArray;
+
+        ArnField::VARIANTS.iter().map(|field| {
+            let value = match self.get(*field) {
+                Component::None => None,
+                Component::Any => Some("*"),
+                Component::Value(v) => Some(v),
+            };
+            (*field, value)
+        })
+    }
+
+    /// Sets one component, validating it the same way parsing would. The
+    /// partition can't be a wildcard or empty.
+    pub fn set(&mut self, field: ArnField, value: ComponentRef<'_>) -> Result<(), ArnParseError> {
+        fn parse<V: Copy, E>(
+            value: ComponentRef<'_>,
+            f: impl FnOnce(&str) -> Result<V, E>,
+        ) -> Result<Component<V>, E> {
+            Ok(match value {
+                Component::None => Component::None,
+                Component::Any => Component::Any,
+                Component::Value(v) => Component::Value(f(v)?),
+            })
+        }
+
+        match field {
+            ArnField::Partition => {
+                self.partition = match value {
+                    Component::Value(p) => p
+                        .parse()
+                        .map_err(|_| ArnParseError::InvalidPartition(p.to_string()))?,
+                    Component::Any => return Err(ArnParseError::InvalidPartition("*".to_string())),
+                    Component::None => return Err(ArnParseError::InvalidPartition(String::new())),
+                }
+            }
+            ArnField::Service => {
+                self.service = parse(value, |s| {
+                    ArrayString::from(s).map_err(|_| ArnParseError::ServiceTooLong)
+                })?
+            }
+            ArnField::Region => {
+                self.region = parse(value, |r| {
+                    r.parse()
+                        .map_err(|_| ArnParseError::InvalidRegion(r.to_string()))
+                })?
+            }
+            ArnField::Account => {
+                self.account = parse(value, |a| {
+                    ArrayString::from(a).map_err(|_| ArnParseError::AccountTooLong)
+                })?
+            }
+            ArnField::Resource => {
+                self.resource_id = parse(value, |id| {
+                    ArrayString::from(id).map_err(|_| ArnParseError::ResourceIdTooLong)
+                })?
+            }
+        }
+        Ok(())
+    }
+
+    // Empty strings are empty components, as when parsing.
+    fn component(value: &str) -> ComponentRef<'_> {
+        if value.is_empty() {
+            Component::None
+        } else {
+            Component::Value(value)
+        }
+    }
+
+    pub fn set_partition(&mut self, partition: &str) -> Result<(), ArnParseError> {
+        self.set(ArnField::Partition, Self::component(partition))
+    }
+
+    pub fn set_service(&mut self, service: &str) -> Result<(), ArnParseError> {
+        self.set(ArnField::Service, Self::component(service))
+    }
+
+    pub fn set_region(&mut self, region: &str) -> Result<(), ArnParseError> {
+        self.set(ArnField::Region, Self::component(region))
+    }
+
+    pub fn set_account(&mut self, account: &str) -> Result<(), ArnParseError> {
+        self.set(ArnField::Account, Self::component(account))
+    }
+
+    pub fn set_resource_id(&mut self, resource_id: &str) -> Result<(), ArnParseError> {
+        self.set(ArnField::Resource, Self::component(resource_id))
+    }
+
+    /// A 64-bit key that is stable across processes, platforms, and Rust
+    /// versions, suitable for persisting (unlike [`Hash`]).
+    ///
+    /// This is 64-bit FNV-1a over the UTF-8 bytes of the canonical
+    /// [`Display`](fmt::Display) form. It will not change without a major
+    /// version bump.
+    pub fn fingerprint(&self) -> u64 {
+        struct Fnv1a(u64);
+
+        impl fmt::Write for Fnv1a {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for b in s.bytes() {
+                    self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3);
+                }
+                Ok(())
+            }
+        }
+
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        fmt::write(&mut hasher, format_args!("{self}")).unwrap();
+        hasher.0
+    }
 }
 
 impl FromStr for Arn {
     type Err = ArnParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = value.split(':').collect();
-        if parts.len() < 6 {
-            return Err(ArnParseError::InvalidFormat(parts.len()));
-        }
+        Self::from_parts(split_parts(value)?)
+    }
+}
+
+/// Splits an ARN into its six `:`-separated parts, the last keeping any
+/// further colons.
+pub(crate) fn split_parts(value: &str) -> Result<[&str; 6], ArnParseError> {
+    let mut parts = [""; 6];
+    let mut count = 0;
+    for part in value.splitn(6, ':') {
+        parts[count] = part;
+        count += 1;
+    }
+    if count < 6 {
+        return Err(ArnParseError::InvalidFormat(count));
+    }
+    Ok(parts)
+}
+
+impl Arn {
+    /// Parses an ARN straight from a byte buffer without allocating,
+    /// validating UTF-8 in place.
+    pub fn parse_bytes(value: &[u8]) -> Result<Arn, ArnParseError> {
+        let value = std::str::from_utf8(value).map_err(|_| ArnParseError::InvalidUtf8)?;
+        Self::from_parts(split_parts(value)?)
+    }
+
+    // `parts[5]` is everything after the fifth ':', colons included.
+    fn from_parts(parts: [&str; 6]) -> Result<Arn, ArnParseError> {
+        let partition = parts[1]
+            .parse()
+            .map_err(|_| ArnParseError::InvalidPartition(parts[1].to_string()))?;
 
         let service = if parts[2].is_empty() {
             Component::None
@@ -73,16 +300,16 @@ impl FromStr for Arn {
             )
         };
 
-        let resource_part = parts[5..].join(":");
-        let resource_id = if resource_part.is_empty() {
+        let resource_id = if parts[5].is_empty() {
             Component::None
         } else {
             Component::Value(
-                ArrayString::from(&resource_part).map_err(|_| ArnParseError::ResourceIdTooLong)?,
+                ArrayString::from(parts[5]).map_err(|_| ArnParseError::ResourceIdTooLong)?,
             )
         };
 
         Ok(Arn {
+            partition,
             service,
             region,
             account,
@@ -99,12 +326,144 @@ impl TryFrom<String> for Arn {
     }
 }
 
+impl TryFrom<&str> for Arn {
+    type Error = ArnParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&[u8]> for Arn {
+    type Error = ArnParseError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Arn::parse_bytes(value)
+    }
+}
+
 impl From<Arn> for String {
     fn from(arn: Arn) -> String {
         arn.to_string()
     }
 }
 
+// Human-readable formats (JSON, YAML, ...) get the canonical string, binary
+// formats (bincode, postcard, ...) get a component-tagged tuple so they avoid
+// allocating and re-parsing the whole ARN.
+impl Serialize for Arn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            WireArn::from(self).serialize(serializer)
+        }
+    }
+}
+
+// Parses from whatever `&str` the format hands out, borrowed or transient,
+// so owned input isn't copied into a `String` first.
+struct ArnVisitor;
+
+impl de::Visitor<'_> for ArnVisitor {
+    type Value = Arn;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ARN string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Arn, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Arn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ArnVisitor)
+        } else {
+            WireArn::deserialize(deserializer)?
+                .try_into()
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Component")]
+enum WireComponent<'a> {
+    None,
+    Any,
+    Value(#[serde(borrow)] Cow<'a, str>),
+}
+
+impl<'a> WireComponent<'a> {
+    fn new<V: Copy>(component: &'a Component<V>, as_str: impl FnOnce(&'a V) -> &'a str) -> Self {
+        match component {
+            Component::None => Self::None,
+            Component::Any => Self::Any,
+            Component::Value(v) => Self::Value(Cow::Borrowed(as_str(v))),
+        }
+    }
+
+    fn parse<V: Copy>(
+        self,
+        parse: impl FnOnce(&str) -> Result<V, ArnParseError>,
+    ) -> Result<Component<V>, ArnParseError> {
+        Ok(match self {
+            Self::None => Component::None,
+            Self::Any => Component::Any,
+            Self::Value(v) => Component::Value(parse(&v)?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "Arn")]
+struct WireArn<'a>(
+    #[serde(borrow)] Cow<'a, str>,
+    #[serde(borrow)] WireComponent<'a>,
+    #[serde(borrow)] WireComponent<'a>,
+    #[serde(borrow)] WireComponent<'a>,
+    #[serde(borrow)] WireComponent<'a>,
+);
+
+impl<'a> From<&'a Arn> for WireArn<'a> {
+    fn from(arn: &'a Arn) -> Self {
+        WireArn(
+            Cow::Borrowed(arn.partition.as_ref()),
+            WireComponent::new(&arn.service, |s| s.as_str()),
+            WireComponent::new(&arn.region, |r| r.as_ref()),
+            WireComponent::new(&arn.account, |a| a.as_str()),
+            WireComponent::new(&arn.resource_id, |id| id.as_str()),
+        )
+    }
+}
+
+impl TryFrom<WireArn<'_>> for Arn {
+    type Error = ArnParseError;
+
+    fn try_from(
+        WireArn(partition, service, region, account, resource_id): WireArn<'_>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Arn {
+            partition: partition
+                .parse()
+                .map_err(|_| ArnParseError::InvalidPartition(partition.to_string()))?,
+            service: service
+                .parse(|s| ArrayString::from(s).map_err(|_| ArnParseError::ServiceTooLong))?,
+            region: region.parse(|r| {
+                r.parse()
+                    .map_err(|_| ArnParseError::InvalidRegion(r.to_string()))
+            })?,
+            account: account
+                .parse(|a| ArrayString::from(a).map_err(|_| ArnParseError::AccountTooLong))?,
+            resource_id: resource_id
+                .parse(|id| ArrayString::from(id).map_err(|_| ArnParseError::ResourceIdTooLong))?,
+        })
+    }
+}
+
 impl fmt::Display for Arn {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let service = match &self.service {
@@ -131,11 +490,38 @@ impl fmt::Display for Arn {
             Component::None => "",
         };
 
-        write!(
-            f,
-            "arn:aws:{}:{}:{}:{}",
-            service, region, account, resource_id
-        )
+        if f.alternate() {
+            fn label(value: &str) -> &str {
+                if value.is_empty() { "(none)" } else { value }
+            }
+            return write!(
+                f,
+                "partition: {}\nservice:   {}\nregion:    {}\naccount:   {}\nresource:  {}",
+                self.partition.as_ref(),
+                label(service),
+                label(region),
+                label(account),
+                label(resource_id)
+            );
+        }
+
+        let args = format_args!(
+            "arn:{}:{}:{}:{}:{}",
+            self.partition.as_ref(),
+            service,
+            region,
+            account,
+            resource_id
+        );
+        if f.width().is_none() && f.precision().is_none() {
+            return f.write_fmt(args);
+        }
+
+        // Longer than any ARN the component capacities allow, so padding
+        // never needs to allocate.
+        let mut rendered = ArrayString::<192>::new();
+        fmt::write(&mut rendered, args)?;
+        f.pad(&rendered)
     }
 }
 
@@ -145,10 +531,18 @@ impl fmt::Debug for Arn {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct ArnBuilder(Arn);
 
 impl ArnBuilder {
+    pub fn partition<S: AsRef<str>>(mut self, partition: S) -> Result<Self, ArnParseError> {
+        let partition = partition.as_ref();
+        self.0.partition = partition
+            .parse()
+            .map_err(|_| ArnParseError::InvalidPartition(partition.to_string()))?;
+        Ok(self)
+    }
+
     pub fn service<S: AsRef<str>>(mut self, service: S) -> Result<Self, ArnParseError> {
         self.0.service = Component::new(
             service
@@ -164,11 +558,11 @@ impl ArnBuilder {
     }
 
     pub fn region<S: AsRef<str>>(mut self, region: S) -> Result<Self, ArnParseError> {
+        let region = region.as_ref();
         self.0.region = Component::new(
             region
-                .as_ref()
                 .parse()
-                .map_err(|_| ArnParseError::ServiceTooLong)?,
+                .map_err(|_| ArnParseError::InvalidRegion(region.to_string()))?,
         );
         Ok(self)
     }
@@ -220,6 +614,29 @@ impl From<ArnBuilder> for Arn {
     }
 }
 
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug, strum::VariantArray)]
+pub enum ArnField {
+    Partition,
+    Service,
+    Region,
+    Account,
+    Resource,
+}
+
+impl AsRef<str> for ArnField {
+    fn as_ref(&self) -> &str {
+        match self {
+            ArnField::Partition => "partition",
+            ArnField::Service => "service",
+            ArnField::Region => "region",
+            ArnField::Account => "account",
+            ArnField::Resource => "resource",
+        }
+    }
+}
+
+pub type ComponentRef<'a> = Component<&'a str>;
+
 #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum Component<V: Copy> {
     #[default]
@@ -234,137 +651,56 @@ impl<V: Copy> Component<V> {
     }
 }
 
-#[derive(Copy, Clone, Default, Hash, PartialEq, Eq, Debug)]
-pub enum Region {
-    #[default]
-    UsEast1,
-    UsEast2,
-    UsWest1,
-    UsWest2,
-    AfSouth1,
-    ApEast1,
-    ApEast2,
-    ApSouth1,
-    ApSouth2,
-    ApSoutheast1,
-    ApSoutheast2,
-    ApSoutheast3,
-    ApSoutheast4,
-    ApSoutheast5,
-    ApSoutheast7,
-    ApNortheast1,
-    ApNortheast2,
-    ApNortheast3,
-    CaCentral1,
-    CaWest1,
-    EuCentral1,
-    EuCentral2,
-    EuWest1,
-    EuWest2,
-    EuSouth1,
-    EuSouth2,
-    EuNorth1,
-    EuNorth2,
-    IlCentral1,
-    MxCentral1,
-    MeSouth1,
-    MeCentral1,
-    SaEast1,
-}
-
 impl Region {
     pub const GLOBAL: Region = Region::UsEast1;
+
+    /// The known region closest to `s` by edit distance, for "did you mean"
+    /// hints on typos like `us-est-1` or `eu-west1`.
+    pub fn suggest(s: &str) -> Option<Region> {
+        use strum::VariantArray;
+
+        let s = s.trim().to_ascii_lowercase();
+        Region::VARIANTS
+            .iter()
+            .map(|region| (edit_distance(&s, region.as_ref()), *region))
+            .filter(|(distance, _)| *distance <= 3)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, region)| region)
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(cur)
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
 }
 
 #[derive(Error, Debug)]
 pub enum RegionError {
     #[error("Region does not exist: {0}")]
     DoesNotExist(String),
+    #[error("Partition does not exist: {0}")]
+    UnknownPartition(String),
 }
 
-impl AsRef<str> for Region {
-    fn as_ref(&self) -> &str {
-        use Region::*;
-
+impl RegionError {
+    pub fn suggestion(&self) -> Option<Region> {
         match self {
-            UsEast1 => "us-east-1",
-            UsEast2 => "us-east-2",
-            UsWest1 => "us-west-1",
-            UsWest2 => "us-west-2",
-            AfSouth1 => "af-south-1",
-            ApEast1 => "ap-east-1",
-            ApEast2 => "ap-east-2",
-            ApSouth1 => "ap-south-1",
-            ApSouth2 => "ap-south-2",
-            ApSoutheast1 => "ap-southeast-1",
-            ApSoutheast2 => "ap-southeast-2",
-            ApSoutheast3 => "ap-southeast-3",
-            ApSoutheast4 => "ap-southeast-4",
-            ApSoutheast5 => "ap-southeast-5",
-            ApSoutheast7 => "ap-southeast-7",
-            ApNortheast1 => "ap-northeast-1",
-            ApNortheast2 => "ap-northeast-2",
-            ApNortheast3 => "ap-northeast-3",
-            CaCentral1 => "ca-central-1",
-            CaWest1 => "ca-west-1",
-            EuCentral1 => "eu-central-1",
-            EuCentral2 => "eu-central-2",
-            EuWest1 => "eu-west-1",
-            EuWest2 => "eu-west-2",
-            EuSouth1 => "eu-south-1",
-            EuSouth2 => "eu-south-2",
-            EuNorth1 => "eu-north-1",
-            EuNorth2 => "eu-north-2",
-            IlCentral1 => "il-central-1",
-            MxCentral1 => "mx-central-1",
-            MeSouth1 => "me-south-1",
-            MeCentral1 => "me-central-1",
-            SaEast1 => "sa-east-1",
-        }
-    }
-}
-
-impl FromStr for Region {
-    type Err = RegionError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Region::*;
-
-        match s {
-            "us-east-1" => Ok(UsEast1),
-            "us-east-2" => Ok(UsEast2),
-            "us-west-1" => Ok(UsWest1),
-            "us-west-2" => Ok(UsWest2),
-            "af-south-1" => Ok(AfSouth1),
-            "ap-east-1" => Ok(ApEast1),
-            "ap-east-2" => Ok(ApEast2),
-            "ap-south-1" => Ok(ApSouth1),
-            "ap-south-2" => Ok(ApSouth2),
-            "ap-southeast-1" => Ok(ApSoutheast1),
-            "ap-southeast-2" => Ok(ApSoutheast2),
-            "ap-southeast-3" => Ok(ApSoutheast3),
-            "ap-southeast-4" => Ok(ApSoutheast4),
-            "ap-southeast-5" => Ok(ApSoutheast5),
-            "ap-southeast-7" => Ok(ApSoutheast7),
-            "ap-northeast-1" => Ok(ApNortheast1),
-            "ap-northeast-2" => Ok(ApNortheast2),
-            "ap-northeast-3" => Ok(ApNortheast3),
-            "ca-central-1" => Ok(CaCentral1),
-            "ca-west-1" => Ok(CaWest1),
-            "eu-central-1" => Ok(EuCentral1),
-            "eu-central-2" => Ok(EuCentral2),
-            "eu-west-1" => Ok(EuWest1),
-            "eu-west-2" => Ok(EuWest2),
-            "eu-south-1" => Ok(EuSouth1),
-            "eu-south-2" => Ok(EuSouth2),
-            "eu-north-1" => Ok(EuNorth1),
-            "eu-north-2" => Ok(EuNorth2),
-            "il-central-1" => Ok(IlCentral1),
-            "mx-central-1" => Ok(MxCentral1),
-            "me-south-1" => Ok(MeSouth1),
-            "me-central-1" => Ok(MeCentral1),
-            "sa-east-1" => Ok(SaEast1),
-            _ => Err(RegionError::DoesNotExist(s.to_string())),
+            RegionError::DoesNotExist(s) => Region::suggest(s),
+            RegionError::UnknownPartition(_) => None,
         }
     }
 }
@@ -443,6 +779,17 @@ mod tests {
         assert!(matches!(result, Err(ArnParseError::InvalidFormat(3))));
     }
 
+    #[test]
+    fn test_parse_partition() {
+        let original = "arn:aws-cn:s3:cn-north-1:123456789012:bucket";
+        let arn: Arn = original.parse().unwrap();
+        assert_eq!(arn.partition, Partition::AwsCn);
+        assert_eq!(arn.to_string(), original);
+
+        let result = "arn:mars:s3:::bucket".parse::<Arn>();
+        assert!(matches!(result, Err(ArnParseError::InvalidPartition(_))));
+    }
+
     #[test]
     fn test_parse_invalid_region() {
         let result = "arn:aws:s3:invalid-region:123456789012:bucket".parse::<Arn>();
@@ -476,6 +823,7 @@ mod tests {
     #[test]
     fn test_display_basic_arn() {
         let arn = Arn {
+            partition: Partition::Aws,
             service: Component::Value(ArrayString::from("s3").unwrap()),
             region: Component::Value(Region::UsEast1),
             account: Component::Value(ArrayString::from("123456789012").unwrap()),
@@ -487,6 +835,7 @@ mod tests {
     #[test]
     fn test_display_arn_with_wildcards() {
         let arn = Arn {
+            partition: Partition::Aws,
             service: Component::Any,
             region: Component::Any,
             account: Component::Any,
@@ -498,6 +847,7 @@ mod tests {
     #[test]
     fn test_display_arn_with_empty_fields() {
         let arn = Arn {
+            partition: Partition::Aws,
             service: Component::Value(ArrayString::from("iam").unwrap()),
             region: Component::None,
             account: Component::Value(ArrayString::from("123456789012").unwrap()),
@@ -506,6 +856,41 @@ mod tests {
         assert_eq!(arn.to_string(), "arn:aws:iam::123456789012:role/my-role");
     }
 
+    #[test]
+    fn test_display_padding() {
+        let arn: Arn = "arn:aws:s3:::bucket".parse().unwrap();
+        assert_eq!(format!("{:<24}|", arn), "arn:aws:s3:::bucket     |");
+        assert_eq!(format!("{:>24}", arn), "     arn:aws:s3:::bucket");
+        assert_eq!(format!("{:*^23}", arn), "**arn:aws:s3:::bucket**");
+        assert_eq!(format!("{:.7}", arn), "arn:aws");
+        assert_eq!(format!("{:4}", arn), "arn:aws:s3:::bucket");
+
+        let long = Arn {
+            partition: Partition::Aws,
+            service: Component::Value(ArrayString::from(&"s".repeat(32)).unwrap()),
+            region: Component::Value(Region::UsIsofSouth1),
+            account: Component::Value(ArrayString::from("123456789012").unwrap()),
+            resource_id: Component::Value(ArrayString::from(&"r".repeat(64)).unwrap()),
+        };
+        assert_eq!(format!("{:1}", long), long.to_string());
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let arn: Arn = "arn:aws:iam::123456789012:role/my-role".parse().unwrap();
+        assert_eq!(
+            format!("{:#}", arn),
+            "partition: aws\nservice:   iam\nregion:    (none)\naccount:   123456789012\nresource:  role/my-role"
+        );
+        assert_eq!(
+            format!("{:#}", Arn::ANY),
+            "partition: aws\nservice:   *\nregion:    *\naccount:   *\nresource:  *"
+        );
+        let cn: Arn = "arn:aws-cn:s3:::bucket".parse().unwrap();
+        assert!(format!("{:#}", cn).starts_with("partition: aws-cn\n"));
+        assert_eq!(format!("{:#?}", arn), arn.to_string());
+    }
+
     #[test]
     fn test_arn_any_constant() {
         assert_eq!(Arn::ANY.to_string(), "arn:aws:*:*:*:*");
@@ -528,6 +913,7 @@ mod tests {
     #[test]
     fn test_serde_serialization() {
         let arn = Arn {
+            partition: Partition::Aws,
             service: Component::Value(ArrayString::from("s3").unwrap()),
             region: Component::Value(Region::UsEast1),
             account: Component::Value(ArrayString::from("123456789012").unwrap()),
@@ -563,6 +949,362 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_serde_deserialization_borrowed() {
+        let json = r#"{"arn": "arn:aws:s3:us-east-1:123456789012:bucket"}"#;
+        let map: std::collections::HashMap<&str, Arn> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            map["arn"].to_string(),
+            "arn:aws:s3:us-east-1:123456789012:bucket"
+        );
+
+        // Escapes and readers hand out transient strings instead.
+        let escaped = r#""arn:aws:s3:::a\u002fb""#;
+        let arn: Arn = serde_json::from_reader(escaped.as_bytes()).unwrap();
+        assert_eq!(arn.to_string(), "arn:aws:s3:::a/b");
+        let arn: Arn = serde_json::from_str(escaped).unwrap();
+        assert_eq!(arn.to_string(), "arn:aws:s3:::a/b");
+    }
+
+    #[test]
+    fn test_wire_roundtrip() {
+        let arn: Arn = "arn:aws:iam::123456789012:role/my-role".parse().unwrap();
+        let wire = WireArn::from(&arn);
+        assert_eq!(
+            wire,
+            WireArn(
+                "aws".into(),
+                WireComponent::Value("iam".into()),
+                WireComponent::None,
+                WireComponent::Value("123456789012".into()),
+                WireComponent::Value("role/my-role".into()),
+            )
+        );
+        let json = serde_json::to_string(&wire).unwrap();
+        let decoded: WireArn = serde_json::from_str(&json).unwrap();
+        assert_eq!(Arn::try_from(decoded).unwrap(), arn);
+        assert_eq!(Arn::try_from(WireArn::from(&Arn::ANY)).unwrap(), Arn::ANY);
+    }
+
+    // A minimal non-human-readable format: a flat list of strings and
+    // variant indices, enough for `WireArn`.
+    mod tokens {
+        use ::serde::de::{self, IntoDeserializer, value::Error};
+        use ::serde::ser::{self, Impossible};
+
+        #[derive(Debug, PartialEq)]
+        pub enum Token {
+            Str(String),
+            Variant(u32),
+        }
+
+        pub struct Tokens(pub Vec<Token>);
+
+        fn unsupported<T>() -> Result<T, Error> {
+            Err(ser::Error::custom("unsupported"))
+        }
+
+        macro_rules! unsupported {
+            ($($method:ident($ty:ty)),*) => {
+                $(fn $method(self, _: $ty) -> Result<(), Error> {
+                    unsupported()
+                })*
+            };
+        }
+
+        impl ser::Serializer for &mut Tokens {
+            type Ok = ();
+            type Error = Error;
+            type SerializeSeq = Impossible<(), Error>;
+            type SerializeTuple = Impossible<(), Error>;
+            type SerializeTupleStruct = Self;
+            type SerializeTupleVariant = Impossible<(), Error>;
+            type SerializeMap = Impossible<(), Error>;
+            type SerializeStruct = Impossible<(), Error>;
+            type SerializeStructVariant = Impossible<(), Error>;
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            fn serialize_str(self, v: &str) -> Result<(), Error> {
+                self.0.push(Token::Str(v.to_string()));
+                Ok(())
+            }
+
+            fn serialize_unit_variant(self, _: &str, index: u32, _: &str) -> Result<(), Error> {
+                self.0.push(Token::Variant(index));
+                Ok(())
+            }
+
+            fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+                self,
+                _: &str,
+                index: u32,
+                _: &str,
+                value: &T,
+            ) -> Result<(), Error> {
+                self.0.push(Token::Variant(index));
+                value.serialize(self)
+            }
+
+            fn serialize_tuple_struct(self, _: &str, _: usize) -> Result<Self, Error> {
+                Ok(self)
+            }
+
+            unsupported!(
+                serialize_bool(bool),
+                serialize_i8(i8),
+                serialize_i16(i16),
+                serialize_i32(i32),
+                serialize_i64(i64),
+                serialize_u8(u8),
+                serialize_u16(u16),
+                serialize_u32(u32),
+                serialize_u64(u64),
+                serialize_f32(f32),
+                serialize_f64(f64),
+                serialize_char(char),
+                serialize_bytes(&[u8]),
+                serialize_unit_struct(&'static str)
+            );
+
+            fn serialize_none(self) -> Result<(), Error> {
+                unsupported()
+            }
+
+            fn serialize_some<T: ?Sized + ser::Serialize>(self, _: &T) -> Result<(), Error> {
+                unsupported()
+            }
+
+            fn serialize_unit(self) -> Result<(), Error> {
+                unsupported()
+            }
+
+            fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+                self,
+                _: &str,
+                _: &T,
+            ) -> Result<(), Error> {
+                unsupported()
+            }
+
+            fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+                unsupported()
+            }
+
+            fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+                unsupported()
+            }
+
+            fn serialize_tuple_variant(
+                self,
+                _: &str,
+                _: u32,
+                _: &str,
+                _: usize,
+            ) -> Result<Self::SerializeTupleVariant, Error> {
+                unsupported()
+            }
+
+            fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+                unsupported()
+            }
+
+            fn serialize_struct(self, _: &str, _: usize) -> Result<Self::SerializeStruct, Error> {
+                unsupported()
+            }
+
+            fn serialize_struct_variant(
+                self,
+                _: &str,
+                _: u32,
+                _: &str,
+                _: usize,
+            ) -> Result<Self::SerializeStructVariant, Error> {
+                unsupported()
+            }
+        }
+
+        impl ser::SerializeTupleStruct for &mut Tokens {
+            type Ok = ();
+            type Error = Error;
+
+            fn serialize_field<T: ?Sized + ser::Serialize>(
+                &mut self,
+                value: &T,
+            ) -> Result<(), Error> {
+                value.serialize(&mut **self)
+            }
+
+            fn end(self) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        pub struct Reader<'de>(pub std::slice::Iter<'de, Token>);
+
+        impl Reader<'_> {
+            fn next(&mut self) -> Result<&Token, Error> {
+                self.0
+                    .next()
+                    .ok_or_else(|| de::Error::custom("end of input"))
+            }
+        }
+
+        impl<'de> de::Deserializer<'de> for &mut Reader<'de> {
+            type Error = Error;
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            fn deserialize_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                Err(de::Error::custom("unsupported"))
+            }
+
+            fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.0.next() {
+                    Some(Token::Str(s)) => visitor.visit_borrowed_str(s),
+                    other => Err(de::Error::custom(format!(
+                        "expected a string, got {other:?}"
+                    ))),
+                }
+            }
+
+            fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+                self,
+                _: &str,
+                _: usize,
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                visitor.visit_seq(self)
+            }
+
+            fn deserialize_enum<V: de::Visitor<'de>>(
+                self,
+                _: &str,
+                _: &[&str],
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                visitor.visit_enum(self)
+            }
+
+            ::serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char string bytes byte_buf
+                option unit unit_struct newtype_struct seq tuple map struct identifier
+                ignored_any
+            }
+        }
+
+        impl<'de> de::SeqAccess<'de> for Reader<'de> {
+            type Error = Error;
+
+            fn next_element_seed<T: de::DeserializeSeed<'de>>(
+                &mut self,
+                seed: T,
+            ) -> Result<Option<T::Value>, Error> {
+                seed.deserialize(self).map(Some)
+            }
+        }
+
+        impl<'de> de::EnumAccess<'de> for &mut Reader<'de> {
+            type Error = Error;
+            type Variant = Self;
+
+            fn variant_seed<V: de::DeserializeSeed<'de>>(
+                self,
+                seed: V,
+            ) -> Result<(V::Value, Self), Error> {
+                let Token::Variant(index) = *self.next()? else {
+                    return Err(de::Error::custom("expected a variant"));
+                };
+                let variant =
+                    seed.deserialize(IntoDeserializer::<Error>::into_deserializer(index))?;
+                Ok((variant, self))
+            }
+        }
+
+        impl<'de> de::VariantAccess<'de> for &mut Reader<'de> {
+            type Error = Error;
+
+            fn unit_variant(self) -> Result<(), Error> {
+                Ok(())
+            }
+
+            fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+                self,
+                seed: T,
+            ) -> Result<T::Value, Error> {
+                seed.deserialize(self)
+            }
+
+            fn tuple_variant<V: de::Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, Error> {
+                Err(de::Error::custom("unsupported"))
+            }
+
+            fn struct_variant<V: de::Visitor<'de>>(
+                self,
+                _: &[&str],
+                _: V,
+            ) -> Result<V::Value, Error> {
+                Err(de::Error::custom("unsupported"))
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde_binary_roundtrip() {
+        use tokens::{Reader, Token, Tokens};
+
+        for input in [
+            "arn:aws:iam::123456789012:role/my-role",
+            "arn:aws-cn:s3:cn-north-1:*:bucket",
+        ] {
+            let arn: Arn = input.parse().unwrap();
+            let mut tokens = Tokens(Vec::new());
+            arn.serialize(&mut tokens).unwrap();
+            assert!(!tokens.0.iter().any(|t| *t == Token::Str(input.to_string())));
+            let decoded = Arn::deserialize(&mut Reader(tokens.0.iter())).unwrap();
+            assert_eq!(decoded, arn);
+        }
+
+        let mut tokens = Tokens(Vec::new());
+        Arn::ANY.serialize(&mut tokens).unwrap();
+        assert_eq!(
+            tokens.0,
+            [
+                Token::Str("aws".to_string()),
+                Token::Variant(1),
+                Token::Variant(1),
+                Token::Variant(1),
+                Token::Variant(1),
+            ]
+        );
+        assert_eq!(
+            Arn::deserialize(&mut Reader(tokens.0.iter())).unwrap(),
+            Arn::ANY
+        );
+
+        tokens.0[0] = Token::Str("aws-mars".to_string());
+        assert!(Arn::deserialize(&mut Reader(tokens.0.iter())).is_err());
+    }
+
+    #[test]
+    fn test_wire_invalid_region() {
+        let wire = WireArn(
+            "aws".into(),
+            WireComponent::Value("s3".into()),
+            WireComponent::Value("mars-north-1".into()),
+            WireComponent::None,
+            WireComponent::None,
+        );
+        assert!(matches!(
+            Arn::try_from(wire),
+            Err(ArnParseError::InvalidRegion(_))
+        ));
+    }
+
     #[test]
     fn test_equality_and_hashing() {
         let arn1: Arn = "arn:aws:s3:us-east-1:123456789012:bucket".parse().unwrap();
@@ -581,6 +1323,14 @@ mod tests {
         assert!(!set.contains(&arn3));
     }
 
+    #[test]
+    fn test_fingerprint_is_stable() {
+        // Pinned values: changing these is a breaking change.
+        let arn: Arn = "arn:aws:s3:us-east-1:123456789012:bucket".parse().unwrap();
+        assert_eq!(arn.fingerprint(), 0xb0e2_2245_3513_7213);
+        assert_eq!(Arn::default().fingerprint(), 0xcfb8_4ab2_fd94_c2eb);
+    }
+
     #[test]
     fn test_debug_display() {
         let arn: Arn = "arn:aws:s3:us-east-1:123456789012:bucket".parse().unwrap();
@@ -596,6 +1346,41 @@ mod tests {
         assert_eq!(arn.region, Component::Value(Region::EuWest1));
     }
 
+    #[test]
+    fn test_region_tables() {
+        use strum::VariantArray;
+
+        for region in Region::VARIANTS {
+            assert_eq!(region.as_ref().parse::<Region>().unwrap(), *region);
+            assert!(!region.description().is_empty());
+        }
+        for partition in Partition::VARIANTS {
+            assert_eq!(partition.as_ref().parse::<Partition>().unwrap(), *partition);
+        }
+        assert_eq!(Region::default(), Region::UsEast1);
+        assert_eq!(Region::UsEast1.partition(), Partition::Aws);
+        assert_eq!(Region::CnNorth1.partition(), Partition::AwsCn);
+        assert_eq!(Partition::AwsCn.dns_suffix(), "amazonaws.com.cn");
+        assert!(matches!(
+            "aws-mars".parse::<Partition>(),
+            Err(RegionError::UnknownPartition(_))
+        ));
+    }
+
+    #[test]
+    fn test_region_suggest() {
+        assert_eq!(Region::suggest("us-est-1"), Some(Region::UsEast1));
+        assert_eq!(Region::suggest("eu-west1"), Some(Region::EuWest1));
+        assert_eq!(
+            Region::suggest("AP-SOUTHEAST-2"),
+            Some(Region::ApSoutheast2)
+        );
+        assert_eq!(Region::suggest("nowhere"), None);
+
+        let err = "us-wesst-2".parse::<Region>().unwrap_err();
+        assert_eq!(err.suggestion(), Some(Region::UsWest2));
+    }
+
     #[test]
     fn test_from_string_conversion() {
         let arn_string = "arn:aws:s3:us-east-1:123456789012:bucket".to_string();
@@ -606,6 +1391,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_str_and_bytes() {
+        let expected: Arn = "arn:aws:s3:us-east-1:123456789012:bucket".parse().unwrap();
+        assert_eq!(
+            Arn::try_from("arn:aws:s3:us-east-1:123456789012:bucket").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Arn::try_from(&b"arn:aws:s3:us-east-1:123456789012:bucket"[..]).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            Arn::try_from(&b"arn:aws:s3:::\xff"[..]),
+            Err(ArnParseError::InvalidUtf8)
+        ));
+        assert!(matches!(
+            Arn::try_from("arn:aws"),
+            Err(ArnParseError::InvalidFormat(2))
+        ));
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let arn =
+            Arn::parse_bytes(b"arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST").unwrap();
+        assert_eq!(
+            arn,
+            "arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST"
+                .parse()
+                .unwrap()
+        );
+        assert!(matches!(
+            Arn::parse_bytes(b"arn:aws:s3"),
+            Err(ArnParseError::InvalidFormat(3))
+        ));
+        assert!(matches!(
+            Arn::parse_bytes(b"arn:aws:s\xc3:::bucket"),
+            Err(ArnParseError::InvalidUtf8)
+        ));
+        assert!(matches!(
+            Arn::parse_bytes("arn:aws:s3:::b\u{fc}cket".as_bytes()),
+            Ok(Arn {
+                resource_id: Component::Value(id),
+                ..
+            }) if id.as_str() == "b\u{fc}cket"
+        ));
+    }
+
     #[test]
     fn test_into_string_conversion() {
         let arn: Arn = "arn:aws:s3:us-east-1:123456789012:bucket".parse().unwrap();
@@ -613,6 +1446,109 @@ mod tests {
         assert_eq!(arn_string, "arn:aws:s3:us-east-1:123456789012:bucket");
     }
 
+    #[test]
+    fn test_get_field() {
+        let arn: Arn = "arn:aws:iam::123456789012:role/my-role".parse().unwrap();
+        assert_eq!(arn.get(ArnField::Partition), Component::Value("aws"));
+        assert_eq!(arn.get(ArnField::Service), Component::Value("iam"));
+        assert_eq!(arn.get(ArnField::Region), Component::None);
+        assert_eq!(arn.get(ArnField::Account), Component::Value("123456789012"));
+        assert_eq!(
+            arn.get(ArnField::Resource),
+            Component::Value("role/my-role")
+        );
+        assert_eq!(Arn::ANY.get(ArnField::Region), Component::Any);
+    }
+
+    #[test]
+    fn test_components() {
+        let arn: Arn = "arn:aws:iam::*:role/my-role".parse().unwrap();
+        let components: Vec<_> = arn.components().collect();
+        assert_eq!(
+            components,
+            [
+                (ArnField::Partition, Some("aws")),
+                (ArnField::Service, Some("iam")),
+                (ArnField::Region, None),
+                (ArnField::Account, Some("*")),
+                (ArnField::Resource, Some("role/my-role")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_field() {
+        let mut arn = Arn::default();
+        arn.set(ArnField::Partition, Component::Value("aws-us-gov"))
+            .unwrap();
+        arn.set(ArnField::Service, Component::Value("s3")).unwrap();
+        arn.set(ArnField::Region, Component::Value("us-gov-west-1"))
+            .unwrap();
+        arn.set(ArnField::Account, Component::Any).unwrap();
+        arn.set(ArnField::Resource, Component::Value("bucket"))
+            .unwrap();
+        assert_eq!(arn.to_string(), "arn:aws-us-gov:s3:us-gov-west-1:*:bucket");
+
+        assert!(matches!(
+            arn.set(ArnField::Partition, Component::Any),
+            Err(ArnParseError::InvalidPartition(_))
+        ));
+        assert!(matches!(
+            arn.set(ArnField::Region, Component::Value("nowhere-1")),
+            Err(ArnParseError::InvalidRegion(_))
+        ));
+        assert!(matches!(
+            arn.set(ArnField::Account, Component::Value("1234567890123")),
+            Err(ArnParseError::AccountTooLong)
+        ));
+        assert_eq!(arn.to_string(), "arn:aws-us-gov:s3:us-gov-west-1:*:bucket");
+    }
+
+    #[test]
+    fn test_setters() {
+        let mut arn: Arn = "arn:aws:s3:::bucket".parse().unwrap();
+        arn.set_service("lambda").unwrap();
+        arn.set_region("eu-west-1").unwrap();
+        arn.set_account("123456789012").unwrap();
+        arn.set_resource_id("function:f").unwrap();
+        assert_eq!(
+            arn.to_string(),
+            "arn:aws:lambda:eu-west-1:123456789012:function:f"
+        );
+
+        arn.set_region("").unwrap();
+        assert_eq!(arn.region, Component::None);
+        arn.set_partition("aws-cn").unwrap();
+        assert_eq!(arn.partition, Partition::AwsCn);
+    }
+
+    #[test]
+    fn test_setters_validate() {
+        let mut arn: Arn = "arn:aws:s3:::bucket".parse().unwrap();
+        let original = arn;
+        assert!(matches!(
+            arn.set_service(&"a".repeat(33)),
+            Err(ArnParseError::ServiceTooLong)
+        ));
+        assert!(matches!(
+            arn.set_region("us-east-9"),
+            Err(ArnParseError::InvalidRegion(_))
+        ));
+        assert!(matches!(
+            arn.set_account(&"1".repeat(13)),
+            Err(ArnParseError::AccountTooLong)
+        ));
+        assert!(matches!(
+            arn.set_resource_id(&"a".repeat(65)),
+            Err(ArnParseError::ResourceIdTooLong)
+        ));
+        assert!(matches!(
+            arn.set_partition(""),
+            Err(ArnParseError::InvalidPartition(_))
+        ));
+        assert_eq!(arn, original);
+    }
+
     #[test]
     fn test_builder_service() {
         let arn: Arn = Arn::builder().service("redshift").unwrap().into();
@@ -627,6 +1563,20 @@ mod tests {
         assert_eq!(arn_string, "arn:aws::us-east-1::");
     }
 
+    #[test]
+    fn test_builder_partition() {
+        let arn: Arn = Arn::builder().partition("aws-cn").unwrap().into();
+        assert_eq!(arn.to_string(), "arn:aws-cn::::");
+        let result = Arn::builder().partition("azure");
+        assert!(matches!(result, Err(ArnParseError::InvalidPartition(_))));
+    }
+
+    #[test]
+    fn test_builder_invalid_region() {
+        let result = Arn::builder().region("us-middle-1");
+        assert!(matches!(result, Err(ArnParseError::InvalidRegion(_))));
+    }
+
     #[test]
     fn test_builder_account() {
         let arn: Arn = Arn::builder().account("123412341234").unwrap().into();
@@ -0,0 +1,179 @@
+//! CloudWatch Logs log group and log stream ARNs.
+
+use crate::{
+    Arn, ArnField, ArnParseError,
+    typed::{resource, resource_of},
+    validate::ValidationError,
+};
+
+const SERVICE: &str = "logs";
+const STREAM: &str = ":log-stream:";
+
+/// `arn:aws:logs:{region}:{account}:log-group:{name}`, optionally followed
+/// by the `:*` that `DescribeLogGroups` and IAM policies use.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct LogGroupArn(Arn);
+
+typed_arn!(LogGroupArn);
+
+impl LogGroupArn {
+    fn rest(&self) -> &str {
+        &resource_of(&self.0)["log-group:".len()..]
+    }
+
+    /// The log group name, e.g. `/aws/lambda/my-function`.
+    pub fn name(&self) -> &str {
+        let rest = self.rest();
+        rest.strip_suffix(":*").unwrap_or(rest)
+    }
+
+    /// Whether the ARN ends in `:*`.
+    pub fn has_wildcard(&self) -> bool {
+        self.rest().ends_with(":*")
+    }
+
+    /// This ARN without a trailing `:*`, as most Logs APIs expect.
+    pub fn without_wildcard(&self) -> LogGroupArn {
+        let mut arn = self.0;
+        arn.set_resource_id(&format!("log-group:{}", self.name()))
+            .unwrap();
+        LogGroupArn(arn)
+    }
+
+    /// This ARN with a trailing `:*`, as IAM policies expect.
+    pub fn with_wildcard(&self) -> Result<LogGroupArn, ArnParseError> {
+        let mut arn = self.0;
+        arn.set_resource_id(&format!("log-group:{}:*", self.name()))?;
+        Ok(LogGroupArn(arn))
+    }
+
+    /// The ARN of the stream `name` in this group.
+    pub fn stream(&self, name: &str) -> Result<LogStreamArn, ValidationError> {
+        let mut arn = self.0;
+        arn.set_resource_id(&format!("log-group:{}{STREAM}{name}", self.name()))?;
+        arn.try_into()
+    }
+}
+
+impl TryFrom<Arn> for LogGroupArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        let rest = resource(&arn, SERVICE, "log-group:")?;
+        let name = rest.strip_suffix(":*").unwrap_or(rest);
+        if name.is_empty() || name.contains(':') {
+            return Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{rest}' is not a log group name"),
+            ));
+        }
+        Ok(LogGroupArn(arn))
+    }
+}
+
+/// `arn:aws:logs:{region}:{account}:log-group:{group}:log-stream:{stream}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct LogStreamArn(Arn);
+
+typed_arn!(LogStreamArn);
+
+impl LogStreamArn {
+    fn parts(&self) -> (&str, &str) {
+        resource_of(&self.0)["log-group:".len()..]
+            .split_once(STREAM)
+            .unwrap()
+    }
+
+    pub fn group_name(&self) -> &str {
+        self.parts().0
+    }
+
+    pub fn stream_name(&self) -> &str {
+        self.parts().1
+    }
+
+    /// The ARN of the group this stream is in.
+    pub fn group(&self) -> LogGroupArn {
+        let mut arn = self.0;
+        arn.set_resource_id(&format!("log-group:{}", self.group_name()))
+            .unwrap();
+        LogGroupArn(arn)
+    }
+}
+
+impl TryFrom<Arn> for LogStreamArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        let rest = resource(&arn, SERVICE, "log-group:")?;
+        match rest.split_once(STREAM) {
+            Some((group, stream))
+                if !group.is_empty()
+                    && !group.contains(':')
+                    && !stream.is_empty()
+                    && !stream.contains([':', '*']) =>
+            {
+                Ok(LogStreamArn(arn))
+            }
+            _ => Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{rest}' is not {{group}}{STREAM}{{stream}}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREFIX: &str = "arn:aws:logs:us-east-1:123456789012";
+
+    #[test]
+    fn test_log_group() {
+        let group: LogGroupArn = format!("{PREFIX}:log-group:/aws/lambda/foo:*")
+            .parse()
+            .unwrap();
+        assert_eq!(group.name(), "/aws/lambda/foo");
+        assert!(group.has_wildcard());
+        let bare = group.without_wildcard();
+        assert_eq!(
+            bare.to_string(),
+            format!("{PREFIX}:log-group:/aws/lambda/foo")
+        );
+        assert!(!bare.has_wildcard());
+        assert_eq!(bare.name(), group.name());
+        assert_eq!(bare.with_wildcard().unwrap(), group);
+
+        for bad in [
+            "log-group:",
+            "log-group::*",
+            "log-group:a:b",
+            "log-stream:x",
+        ] {
+            assert!(
+                format!("{PREFIX}:{bad}").parse::<LogGroupArn>().is_err(),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_log_stream() {
+        let group: LogGroupArn = format!("{PREFIX}:log-group:app:*").parse().unwrap();
+        let stream = group.stream("2024/01/01/[$LATEST]abc").unwrap();
+        assert_eq!(
+            stream.to_string(),
+            format!("{PREFIX}:log-group:app:log-stream:2024/01/01/[$LATEST]abc")
+        );
+        assert_eq!(stream.group_name(), "app");
+        assert_eq!(stream.stream_name(), "2024/01/01/[$LATEST]abc");
+        assert_eq!(stream.group(), group.without_wildcard());
+        assert!(group.stream("a:b").is_err());
+        assert!(
+            format!("{PREFIX}:log-group:app")
+                .parse::<LogStreamArn>()
+                .is_err()
+        );
+    }
+}
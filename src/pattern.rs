@@ -0,0 +1,232 @@
+use crate::{Arn, ArnField, ArnParseError, Component};
+use arrayvec::ArrayString;
+use std::{fmt, str::FromStr};
+
+/// An IAM-style ARN pattern such as `arn:aws:s3:*:*:logs/*`.
+///
+/// Each component is matched separately; `*` matches any run of characters
+/// (including `/` and `:` in the resource) and `?` matches exactly one.
+#[derive(Default, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct ArnPattern {
+    partition: ArrayString<16>,
+    service: ArrayString<32>,
+    region: ArrayString<32>,
+    account: ArrayString<12>,
+    resource_id: ArrayString<64>,
+}
+
+impl ArnPattern {
+    pub fn get(&self, field: ArnField) -> &str {
+        match field {
+            ArnField::Partition => &self.partition,
+            ArnField::Service => &self.service,
+            ArnField::Region => &self.region,
+            ArnField::Account => &self.account,
+            ArnField::Resource => &self.resource_id,
+        }
+    }
+
+    pub fn matches(&self, arn: &Arn) -> bool {
+        use strum::VariantArray;
+
+        ArnField::VARIANTS.iter().all(|field| {
+            let value = match arn.get(*field) {
+                Component::None => "",
+                Component::Any => "*",
+                Component::Value(v) => v,
+            };
+            glob(self.get(*field), value)
+        })
+    }
+}
+
+fn char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+fn glob(pattern: &str, value: &str) -> bool {
+    let (p, v) = (pattern.as_bytes(), value.as_bytes());
+    let (mut pi, mut vi) = (0, 0);
+    // Where the last `*` was seen and how much of `value` it has swallowed.
+    let mut star = None;
+
+    while vi < v.len() {
+        match p.get(pi) {
+            Some(b'*') => {
+                star = Some((pi, vi));
+                pi += 1;
+            }
+            Some(b'?') => {
+                pi += 1;
+                vi += char_len(v[vi]);
+            }
+            Some(b) if *b == v[vi] => {
+                pi += 1;
+                vi += 1;
+            }
+            _ => match star {
+                Some((star_pi, star_vi)) => {
+                    let next = star_vi + char_len(v[star_vi]);
+                    star = Some((star_pi, next));
+                    pi = star_pi + 1;
+                    vi = next;
+                }
+                None => return false,
+            },
+        }
+    }
+    p[pi..].iter().all(|b| *b == b'*')
+}
+
+impl FromStr for ArnPattern {
+    type Err = ArnParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts = crate::split_parts(value)?;
+        Ok(ArnPattern {
+            partition: ArrayString::from(parts[1])
+                .map_err(|_| ArnParseError::InvalidPartition(parts[1].to_string()))?,
+            service: ArrayString::from(parts[2]).map_err(|_| ArnParseError::ServiceTooLong)?,
+            region: ArrayString::from(parts[3])
+                .map_err(|_| ArnParseError::InvalidRegion(parts[3].to_string()))?,
+            account: ArrayString::from(parts[4]).map_err(|_| ArnParseError::AccountTooLong)?,
+            resource_id: ArrayString::from(parts[5])
+                .map_err(|_| ArnParseError::ResourceIdTooLong)?,
+        })
+    }
+}
+
+// The literal pattern for a component, with `Any` as `*`.
+fn literal<const N: usize>(component: Component<ArrayString<N>>) -> ArrayString<N> {
+    match component {
+        Component::None => ArrayString::new(),
+        Component::Any => ArrayString::from("*").unwrap(),
+        Component::Value(v) => v,
+    }
+}
+
+impl From<&Arn> for ArnPattern {
+    fn from(arn: &Arn) -> Self {
+        // Every partition and region name fits; see `test_names_fit`.
+        let region = match arn.region {
+            Component::None => Component::None,
+            Component::Any => Component::Any,
+            Component::Value(r) => Component::Value(ArrayString::from(r.as_ref()).unwrap()),
+        };
+        ArnPattern {
+            partition: ArrayString::from(arn.partition.as_ref()).unwrap(),
+            service: literal(arn.service),
+            region: literal(region),
+            account: literal(arn.account),
+            resource_id: literal(arn.resource_id),
+        }
+    }
+}
+
+impl fmt::Display for ArnPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:{}:{}:{}:{}",
+            self.partition, self.service, self.region, self.account, self.resource_id
+        )
+    }
+}
+
+impl fmt::Debug for ArnPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, arn: &str) -> bool {
+        let pattern: ArnPattern = pattern.parse().unwrap();
+        pattern.matches(&arn.parse().unwrap())
+    }
+
+    #[test]
+    fn test_glob() {
+        assert!(glob("", ""));
+        assert!(glob("*", ""));
+        assert!(glob("*", "anything"));
+        assert!(glob("a*c", "abbbc"));
+        assert!(glob("a?c", "abc"));
+        assert!(glob("a?c", "a\u{fc}c"));
+        assert!(glob("*/*", "logs/2024/01"));
+        assert!(!glob("a?c", "ac"));
+        assert!(!glob("abc", "abcd"));
+        assert!(!glob("", "a"));
+        assert!(glob("a*b*c", "aXbYbZc"));
+        assert!(!glob("a*b*c", "aXbYbZ"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let arn = "arn:aws:s3:::my-bucket/logs/2024/01.gz";
+        assert!(matches("arn:aws:s3:::my-bucket/*", arn));
+        assert!(matches("arn:*:s3:*:*:*", arn));
+        assert!(matches("arn:aws:s3:::*/logs/*.gz", arn));
+        assert!(!matches("arn:aws:s3:::other-bucket/*", arn));
+        assert!(!matches("arn:aws-cn:s3:::*", arn));
+
+        let arn = "arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST";
+        assert!(matches("arn:aws:lambda:us-*:123456789012:function:*", arn));
+        assert!(matches("arn:aws:lambda:us-east-?:*:function:f:*", arn));
+        assert!(!matches("arn:aws:lambda:eu-*:*:*", arn));
+        assert!(!matches(
+            "arn:aws:lambda:us-east-1:123456789012:function:f",
+            arn
+        ));
+    }
+
+    #[test]
+    fn test_empty_components_match_only_empty() {
+        assert!(matches("arn:aws:iam:::role/*", "arn:aws:iam:::role/r"));
+        assert!(!matches(
+            "arn:aws:iam:::role/*",
+            "arn:aws:iam::123456789012:role/r"
+        ));
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let original = "arn:aws:ec2:*:123456789012:instance/*";
+        let pattern: ArnPattern = original.parse().unwrap();
+        assert_eq!(pattern.to_string(), original);
+        assert_eq!(pattern.get(ArnField::Region), "*");
+        assert!(matches!(
+            "arn:aws:s3".parse::<ArnPattern>(),
+            Err(ArnParseError::InvalidFormat(3))
+        ));
+        assert_eq!(ArnPattern::from(&Arn::ANY).to_string(), "arn:aws:*:*:*:*");
+        assert!(ArnPattern::from(&Arn::ANY).matches(&Arn::default()));
+
+        for input in [
+            "arn:aws-us-gov:iam::123456789012:role/r",
+            "arn:aws:s3:eu-west-1::bucket/a:b",
+        ] {
+            let arn: Arn = input.parse().unwrap();
+            let pattern = ArnPattern::from(&arn);
+            assert_eq!(pattern.to_string(), input);
+            assert!(pattern.matches(&arn));
+        }
+    }
+
+    #[test]
+    fn test_names_fit() {
+        use crate::{Partition, Region};
+        use strum::VariantArray;
+
+        assert!(Partition::VARIANTS.iter().all(|p| p.as_ref().len() <= 16));
+        assert!(Region::VARIANTS.iter().all(|r| r.as_ref().len() <= 32));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Arn, ArnField, ArnParseError, Component};
+use arrayvec::ArrayString;
+
+/// Resource types qualified by a version or alias, by service. Elsewhere
+/// colons are part of the name (`log-group:g:log-stream:s`, RDS automated
+/// snapshots, Step Functions executions) and the last segment is no
+/// qualifier.
+const QUALIFIED: &[(&str, &str)] = &[
+    ("batch", "job-definition"),
+    ("ecs", "task-definition"),
+    ("lambda", "function"),
+    ("lambda", "layer"),
+    ("states", "stateMachine"),
+];
+
+// Byte offset of the ':' introducing the qualifier, if any: the last ':'
+// after the name of a resource type in `QUALIFIED`.
+fn qualifier_start(arn: &Arn) -> Option<(&str, usize)> {
+    let (Component::Value(service), Component::Value(resource)) =
+        (arn.get(ArnField::Service), arn.get(ArnField::Resource))
+    else {
+        return None;
+    };
+    let type_end = resource.find([':', '/'])?;
+    if !QUALIFIED.contains(&(service, &resource[..type_end])) {
+        return None;
+    }
+    let name_start = type_end + 1;
+    let i = resource[name_start..].rfind(':')?;
+    Some((resource, name_start + i))
+}
+
+impl Arn {
+    /// The trailing `:`-separated segment of a versioned or aliased
+    /// resource, e.g. `$LATEST` in `function:my-function:$LATEST` or `1` in
+    /// `task-definition/app:1`.
+    pub fn qualifier(&self) -> Option<&str> {
+        qualifier_start(self).map(|(id, i)| &id[i + 1..])
+    }
+
+    /// This ARN with any [qualifier](Self::qualifier) removed.
+    pub fn without_qualifier(&self) -> Arn {
+        let mut arn = *self;
+        if let Some((_, i)) = qualifier_start(self)
+            && let Component::Value(id) = &mut arn.resource_id
+        {
+            id.truncate(i);
+        }
+        arn
+    }
+
+    /// This ARN with its [qualifier](Self::qualifier) replaced by, or
+    /// appended as, `qualifier`. Fails if [`qualifier`](Self::qualifier)
+    /// wouldn't return `qualifier` for the result, e.g. for a resource type
+    /// that takes no qualifier, or a qualifier containing `:`.
+    pub fn with_qualifier(&self, qualifier: &str) -> Result<Arn, ArnParseError> {
+        let mut arn = self.without_qualifier();
+        let mut id = match arn.resource_id {
+            Component::Value(id) => id,
+            _ => ArrayString::new(),
+        };
+        for part in [":", qualifier] {
+            id.try_push_str(part)
+                .map_err(|_| ArnParseError::ResourceIdTooLong)?;
+        }
+        arn.resource_id = Component::Value(id);
+        if arn.qualifier() != Some(qualifier) {
+            return Err(ArnParseError::InvalidQualifier(qualifier.to_string()));
+        }
+        Ok(arn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arn(s: &str) -> Arn {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_qualifier() {
+        let qualified = arn("arn:aws:lambda:us-east-1:123456789012:function:f:$LATEST");
+        assert_eq!(qualified.qualifier(), Some("$LATEST"));
+        assert_eq!(
+            qualified.without_qualifier(),
+            arn("arn:aws:lambda:us-east-1:123456789012:function:f")
+        );
+        assert_eq!(
+            arn("arn:aws:ecs:us-east-1:123456789012:task-definition/app:7").qualifier(),
+            Some("7")
+        );
+        assert_eq!(
+            arn("arn:aws:lambda:us-east-1:123456789012:function:f").qualifier(),
+            None
+        );
+        assert_eq!(arn("arn:aws:s3:::bucket/a:b").qualifier(), None);
+        assert_eq!(
+            arn("arn:aws:states:us-east-1:123456789012:stateMachine:m:prod").qualifier(),
+            Some("prod")
+        );
+        assert_eq!(
+            arn("arn:aws:lambda:us-east-1:123456789012:layer:l:3").qualifier(),
+            Some("3")
+        );
+        assert_eq!(arn("arn:aws:s3:::bucket").qualifier(), None);
+        assert_eq!(Arn::ANY.qualifier(), None);
+        assert_eq!(
+            arn("arn:aws:rds:us-east-1:123456789012:snapshot:rds:my-snap").qualifier(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_colon_delimited_names_are_unqualified() {
+        for input in [
+            "arn:aws:logs:us-east-1:123456789012:log-group:g:log-stream:s",
+            "arn:aws:dynamodb:us-east-1:123456789012:table/t/stream/2015-05-11T21:21:33.291",
+            "arn:aws:states:us-east-1:123456789012:execution:machine:id",
+            "arn:aws:rds:us-east-1:123456789012:snapshot:rds:my-snap",
+        ] {
+            let arn = arn(input);
+            assert_eq!(arn.qualifier(), None, "{input}");
+            assert_eq!(arn.without_qualifier(), arn, "{input}");
+        }
+        let a = arn("arn:aws:logs:us-east-1:123456789012:log-group:g:log-stream:a");
+        let b = arn("arn:aws:logs:us-east-1:123456789012:log-group:g:log-stream:b");
+        assert_ne!(a.without_qualifier(), b.without_qualifier());
+    }
+
+    #[test]
+    fn test_with_qualifier() {
+        let base = arn("arn:aws:lambda:us-east-1:123456789012:function:f");
+        let live = base.with_qualifier("live").unwrap();
+        assert_eq!(live.to_string(), format!("{base}:live"));
+        assert_eq!(
+            live.with_qualifier("1").unwrap().to_string(),
+            format!("{base}:1")
+        );
+        assert_eq!(live.without_qualifier(), base);
+
+        let long = "x".repeat(64);
+        assert!(matches!(
+            base.with_qualifier(&long),
+            Err(ArnParseError::ResourceIdTooLong)
+        ));
+    }
+
+    #[test]
+    fn test_with_qualifier_roundtrip() {
+        for input in [
+            "arn:aws:lambda:us-east-1:123456789012:function:f",
+            "arn:aws:lambda:us-east-1:123456789012:function:f:1",
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/app",
+            "arn:aws:states:us-east-1:123456789012:stateMachine:m",
+            "arn:aws:states:us-east-1:123456789012:execution:m:id",
+            "arn:aws:sns:us-east-1:123456789012:my-topic",
+            "arn:aws:s3:::bucket",
+            "arn:aws:rds:us-east-1:123456789012:snapshot:rds:my-snap",
+            "arn:aws:sqs:us-east-1:123456789012:",
+        ] {
+            let base = arn(input);
+            for qualifier in ["x", "$LATEST", "", "a:b", "a/b"] {
+                match base.with_qualifier(qualifier) {
+                    Ok(qualified) => assert_eq!(qualified.qualifier(), Some(qualifier)),
+                    Err(err) => assert!(
+                        matches!(&err, ArnParseError::InvalidQualifier(q) if q == qualifier),
+                        "{input} {qualifier}: {err}"
+                    ),
+                }
+            }
+        }
+        let topic = arn("arn:aws:sns:us-east-1:123456789012:my-topic");
+        assert!(matches!(
+            topic.with_qualifier("x"),
+            Err(ArnParseError::InvalidQualifier(_))
+        ));
+        assert!(
+            arn("arn:aws:lambda:us-east-1:123456789012:function:f")
+                .with_qualifier("a:b")
+                .is_err()
+        );
+    }
+}
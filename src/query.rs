@@ -0,0 +1,176 @@
+use crate::{Arn, ArnField, ArnPattern, Component, Partition, Region};
+use std::borrow::Borrow;
+
+#[derive(Debug, Clone)]
+enum Filter {
+    Partition(Partition),
+    Service(String),
+    Regions(Vec<Region>),
+    Account(String),
+    ResourcePrefix(String),
+    Pattern(ArnPattern),
+    Either(ArnQuery, ArnQuery),
+    Not(ArnQuery),
+}
+
+impl Filter {
+    fn eval(&self, arn: &Arn) -> bool {
+        match self {
+            Filter::Partition(partition) => arn.partition == *partition,
+            Filter::Service(service) => arn.get(ArnField::Service) == Component::Value(service),
+            Filter::Regions(regions) => {
+                matches!(arn.region, Component::Value(r) if regions.contains(&r))
+            }
+            Filter::Account(account) => arn.get(ArnField::Account) == Component::Value(account),
+            Filter::ResourcePrefix(prefix) => {
+                matches!(arn.get(ArnField::Resource), Component::Value(r) if r.starts_with(prefix.as_str()))
+            }
+            Filter::Pattern(pattern) => pattern.matches(arn),
+            Filter::Either(a, b) => a.eval(arn) || b.eval(arn),
+            Filter::Not(query) => !query.eval(arn),
+        }
+    }
+}
+
+/// A composable ARN filter; every condition added must hold.
+///
+/// ```
+/// use arn::{Arn, ArnQuery, Region};
+///
+/// let query = ArnQuery::new()
+///     .service("s3")
+///     .resource_prefix("logs/")
+///     .or(ArnQuery::new().region_in([Region::UsEast1, Region::UsWest2]));
+/// let arn: Arn = "arn:aws:sqs:us-west-2:123456789012:queue".parse().unwrap();
+/// assert!(query.eval(&arn));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArnQuery(Vec<Filter>);
+
+impl ArnQuery {
+    /// A query matching every ARN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with(mut self, filter: Filter) -> Self {
+        self.0.push(filter);
+        self
+    }
+
+    pub fn partition(self, partition: Partition) -> Self {
+        self.with(Filter::Partition(partition))
+    }
+
+    pub fn service(self, service: impl Into<String>) -> Self {
+        self.with(Filter::Service(service.into()))
+    }
+
+    pub fn region(self, region: Region) -> Self {
+        self.region_in([region])
+    }
+
+    /// ARNs in any of `regions`. Never matches ARNs without a region.
+    pub fn region_in(self, regions: impl IntoIterator<Item = Region>) -> Self {
+        self.with(Filter::Regions(regions.into_iter().collect()))
+    }
+
+    pub fn account(self, account: impl Into<String>) -> Self {
+        self.with(Filter::Account(account.into()))
+    }
+
+    pub fn resource_prefix(self, prefix: impl Into<String>) -> Self {
+        self.with(Filter::ResourcePrefix(prefix.into()))
+    }
+
+    /// ARNs matching an IAM-style wildcard pattern.
+    pub fn pattern(self, pattern: ArnPattern) -> Self {
+        self.with(Filter::Pattern(pattern))
+    }
+
+    /// ARNs matching either this query or `other`.
+    pub fn or(self, other: ArnQuery) -> Self {
+        ArnQuery(vec![Filter::Either(self, other)])
+    }
+
+    /// ARNs matching this query but not `other`.
+    pub fn exclude(self, other: ArnQuery) -> Self {
+        self.with(Filter::Not(other))
+    }
+
+    pub fn eval(&self, arn: &Arn) -> bool {
+        self.0.iter().all(|filter| filter.eval(arn))
+    }
+
+    /// The ARNs in `arns` that match, lazily.
+    pub fn filter_arns<'a, I>(&'a self, arns: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator + 'a,
+        I::Item: Borrow<Arn>,
+    {
+        arns.into_iter().filter(|arn| self.eval(arn.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arns() -> Vec<Arn> {
+        [
+            "arn:aws:s3:::bucket/logs/2024",
+            "arn:aws:s3:::bucket/data",
+            "arn:aws:sqs:us-east-1:123456789012:queue",
+            "arn:aws:sqs:eu-west-1:123456789012:queue",
+            "arn:aws-cn:sqs:cn-north-1:210987654321:queue",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect()
+    }
+
+    fn count(query: &ArnQuery) -> usize {
+        query.filter_arns(&arns()).count()
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(count(&ArnQuery::new()), 5);
+        assert_eq!(count(&ArnQuery::new().service("s3")), 2);
+        assert_eq!(
+            count(
+                &ArnQuery::new()
+                    .service("s3")
+                    .resource_prefix("bucket/logs/")
+            ),
+            1
+        );
+        assert_eq!(
+            count(&ArnQuery::new().region_in([Region::UsEast1, Region::EuWest1])),
+            2
+        );
+        assert_eq!(count(&ArnQuery::new().region(Region::CnNorth1)), 1);
+        assert_eq!(count(&ArnQuery::new().account("123456789012")), 2);
+        assert_eq!(count(&ArnQuery::new().partition(Partition::AwsCn)), 1);
+        assert_eq!(
+            count(&ArnQuery::new().pattern("arn:*:sqs:*:*:*".parse().unwrap())),
+            3
+        );
+    }
+
+    #[test]
+    fn test_composition() {
+        let query = ArnQuery::new()
+            .service("s3")
+            .or(ArnQuery::new().region(Region::EuWest1));
+        assert_eq!(count(&query), 3);
+
+        let query = ArnQuery::new()
+            .service("sqs")
+            .exclude(ArnQuery::new().partition(Partition::AwsCn));
+        assert_eq!(count(&query), 2);
+
+        let owned: Vec<Arn> = query.filter_arns(arns()).collect();
+        assert_eq!(owned.len(), 2);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Arn, ArnParseError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::{borrow::Cow, fmt, ops, str::FromStr};
+
+/// An [`Arn`] that keeps the exact string it was parsed from, so that
+/// re-serializing it is byte-identical to the input whatever normalization
+/// parsing applies.
+///
+/// Comparisons and hashing use the original string.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RawArn {
+    raw: String,
+    arn: Arn,
+}
+
+impl RawArn {
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn arn(&self) -> &Arn {
+        &self.arn
+    }
+
+    /// Whether the original string is the canonical form of the ARN.
+    pub fn is_canonical(&self) -> bool {
+        self.raw == self.arn.to_string()
+    }
+
+    pub fn into_string(self) -> String {
+        self.raw
+    }
+}
+
+impl FromStr for RawArn {
+    type Err = ArnParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(RawArn {
+            raw: value.to_string(),
+            arn: value.parse()?,
+        })
+    }
+}
+
+impl TryFrom<String> for RawArn {
+    type Error = ArnParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let arn = value.parse()?;
+        Ok(RawArn { raw: value, arn })
+    }
+}
+
+impl From<Arn> for RawArn {
+    fn from(arn: Arn) -> Self {
+        RawArn {
+            raw: arn.to_string(),
+            arn,
+        }
+    }
+}
+
+impl From<RawArn> for Arn {
+    fn from(raw: RawArn) -> Self {
+        raw.arn
+    }
+}
+
+impl ops::Deref for RawArn {
+    type Target = Arn;
+
+    fn deref(&self) -> &Self::Target {
+        &self.arn
+    }
+}
+
+impl AsRef<str> for RawArn {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl fmt::Display for RawArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.raw)
+    }
+}
+
+impl fmt::Debug for RawArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Serialize for RawArn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawArn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Cow::<str>::deserialize(deserializer)?;
+        value.into_owned().try_into().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preserves_input() {
+        let input = "arn:aws:s3:::bucket";
+        let raw: RawArn = input.parse().unwrap();
+        assert_eq!(raw.as_str(), input);
+        assert_eq!(raw.to_string(), input);
+        assert_eq!(*raw.arn(), input.parse::<Arn>().unwrap());
+        assert_eq!(raw.service, raw.arn().service);
+        assert!(raw.is_canonical());
+        assert_eq!(raw, RawArn::from(*raw.arn()));
+        assert_eq!(raw.clone().into_string(), input);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let json = "\"arn:aws:iam::123456789012:role/r\"";
+        let raw: RawArn = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&raw).unwrap(), json);
+        assert!(serde_json::from_str::<RawArn>("\"arn:aws\"").is_err());
+    }
+}
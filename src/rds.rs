@@ -0,0 +1,170 @@
+//! RDS ARNs, whose resources are `{kind}:{identifier}`.
+
+use crate::{
+    Arn, ArnField,
+    typed::{resource, resource_of},
+    validate::ValidationError,
+};
+use strum::VariantArray;
+
+const SERVICE: &str = "rds";
+const AUTOMATED: &str = "rds:";
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug, strum::VariantArray)]
+pub enum RdsResourceKind {
+    Instance,
+    Cluster,
+    Snapshot,
+    ClusterSnapshot,
+    ParameterGroup,
+    ClusterParameterGroup,
+    OptionGroup,
+    SubnetGroup,
+    SecurityGroup,
+    EventSubscription,
+    ReservedInstance,
+    Proxy,
+}
+
+impl AsRef<str> for RdsResourceKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            RdsResourceKind::Instance => "db",
+            RdsResourceKind::Cluster => "cluster",
+            RdsResourceKind::Snapshot => "snapshot",
+            RdsResourceKind::ClusterSnapshot => "cluster-snapshot",
+            RdsResourceKind::ParameterGroup => "pg",
+            RdsResourceKind::ClusterParameterGroup => "cluster-pg",
+            RdsResourceKind::OptionGroup => "og",
+            RdsResourceKind::SubnetGroup => "subgrp",
+            RdsResourceKind::SecurityGroup => "secgrp",
+            RdsResourceKind::EventSubscription => "es",
+            RdsResourceKind::ReservedInstance => "ri",
+            RdsResourceKind::Proxy => "db-proxy",
+        }
+    }
+}
+
+impl RdsResourceKind {
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|kind| kind.as_ref() == prefix)
+    }
+
+    fn is_snapshot(&self) -> bool {
+        matches!(
+            self,
+            RdsResourceKind::Snapshot | RdsResourceKind::ClusterSnapshot
+        )
+    }
+}
+
+/// `arn:aws:rds:{region}:{account}:{kind}:{identifier}`, e.g.
+/// `db:my-instance` or, for automated snapshots, `snapshot:rds:my-snap`.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct RdsArn(Arn);
+
+typed_arn!(RdsArn);
+
+impl RdsArn {
+    fn parts(&self) -> (&str, &str) {
+        resource_of(&self.0).split_once(':').unwrap()
+    }
+
+    pub fn kind(&self) -> RdsResourceKind {
+        RdsResourceKind::from_prefix(self.parts().0).unwrap()
+    }
+
+    /// The resource identifier, without the `rds:` of automated snapshots.
+    pub fn identifier(&self) -> &str {
+        let id = self.parts().1;
+        if self.is_automated() {
+            &id[AUTOMATED.len()..]
+        } else {
+            id
+        }
+    }
+
+    /// Whether this is an automated (`rds:`-prefixed) snapshot.
+    pub fn is_automated(&self) -> bool {
+        self.kind().is_snapshot() && self.parts().1.starts_with(AUTOMATED)
+    }
+}
+
+impl TryFrom<Arn> for RdsArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        let resource = resource(&arn, SERVICE, "")?;
+        let invalid = |message: String| Err(ValidationError::invalid(ArnField::Resource, message));
+        let Some((prefix, id)) = resource.split_once(':') else {
+            return invalid(format!("'{resource}' is not {{kind}}:{{identifier}}"));
+        };
+        let Some(kind) = RdsResourceKind::from_prefix(prefix) else {
+            return invalid(format!("unknown RDS resource type '{prefix}'"));
+        };
+        let id = match id.strip_prefix(AUTOMATED) {
+            Some(id) if kind.is_snapshot() => id,
+            _ => id,
+        };
+        if id.is_empty() || id.contains(':') {
+            return invalid(format!("'{id}' is not an RDS identifier"));
+        }
+        Ok(RdsArn(arn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rds(resource: &str) -> Result<RdsArn, ValidationError> {
+        format!("arn:aws:rds:us-east-1:123456789012:{resource}").parse()
+    }
+
+    #[test]
+    fn test_kinds() {
+        let db = rds("db:my-instance").unwrap();
+        assert_eq!(db.kind(), RdsResourceKind::Instance);
+        assert_eq!(db.identifier(), "my-instance");
+        assert!(!db.is_automated());
+
+        let cluster = rds("cluster:my-cluster").unwrap();
+        assert_eq!(cluster.kind(), RdsResourceKind::Cluster);
+        assert_eq!(cluster.identifier(), "my-cluster");
+
+        assert_eq!(
+            rds("cluster-pg:default.aurora8").unwrap().kind(),
+            RdsResourceKind::ClusterParameterGroup
+        );
+        for kind in RdsResourceKind::VARIANTS {
+            let arn = rds(&format!("{}:x", kind.as_ref())).unwrap();
+            assert_eq!(arn.kind(), *kind);
+        }
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let manual = rds("snapshot:my-snap").unwrap();
+        assert_eq!(manual.identifier(), "my-snap");
+        assert!(!manual.is_automated());
+
+        let automated = rds("snapshot:rds:my-db-2024-01-01-00-00").unwrap();
+        assert_eq!(automated.kind(), RdsResourceKind::Snapshot);
+        assert_eq!(automated.identifier(), "my-db-2024-01-01-00-00");
+        assert!(automated.is_automated());
+
+        let cluster = rds("cluster-snapshot:rds:c-1").unwrap();
+        assert_eq!(cluster.kind(), RdsResourceKind::ClusterSnapshot);
+        assert!(cluster.is_automated());
+    }
+
+    #[test]
+    fn test_invalid() {
+        for resource in ["db", "db:", "widget:x", "db:rds:x", "snapshot:rds:"] {
+            assert!(rds(resource).is_err(), "{resource}");
+        }
+    }
+}
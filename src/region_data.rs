@@ -0,0 +1,363 @@
+// @generated by `cargo run --example codegen` from botocore endpoints.json (format version 3). Do not edit.
+
+use crate::RegionError;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Default, Hash, PartialEq, Eq, Debug, strum::VariantArray)]
+pub enum Partition {
+    /// AWS Standard
+    #[default]
+    Aws,
+    /// AWS China
+    AwsCn,
+    /// AWS GovCloud (US)
+    AwsUsGov,
+    /// AWS ISO (US)
+    AwsIso,
+    /// AWS ISOB (US)
+    AwsIsoB,
+    /// AWS ISOE (Europe)
+    AwsIsoE,
+    /// AWS ISOF
+    AwsIsoF,
+    /// AWS EUSC
+    AwsEusc,
+}
+
+impl Partition {
+    pub fn dns_suffix(&self) -> &'static str {
+        match self {
+            Partition::Aws => "amazonaws.com",
+            Partition::AwsCn => "amazonaws.com.cn",
+            Partition::AwsUsGov => "amazonaws.com",
+            Partition::AwsIso => "c2s.ic.gov",
+            Partition::AwsIsoB => "sc2s.sgov.gov",
+            Partition::AwsIsoE => "cloud.adc-e.uk",
+            Partition::AwsIsoF => "csp.hci.ic.gov",
+            Partition::AwsEusc => "amazonaws.eu",
+        }
+    }
+}
+
+impl AsRef<str> for Partition {
+    fn as_ref(&self) -> &str {
+        match self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::AwsIso => "aws-iso",
+            Partition::AwsIsoB => "aws-iso-b",
+            Partition::AwsIsoE => "aws-iso-e",
+            Partition::AwsIsoF => "aws-iso-f",
+            Partition::AwsEusc => "aws-eusc",
+        }
+    }
+}
+
+impl FromStr for Partition {
+    type Err = RegionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aws" => Ok(Partition::Aws),
+            "aws-cn" => Ok(Partition::AwsCn),
+            "aws-us-gov" => Ok(Partition::AwsUsGov),
+            "aws-iso" => Ok(Partition::AwsIso),
+            "aws-iso-b" => Ok(Partition::AwsIsoB),
+            "aws-iso-e" => Ok(Partition::AwsIsoE),
+            "aws-iso-f" => Ok(Partition::AwsIsoF),
+            "aws-eusc" => Ok(Partition::AwsEusc),
+            _ => Err(RegionError::UnknownPartition(s.to_string())),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Hash, PartialEq, Eq, Debug, strum::VariantArray)]
+pub enum Region {
+    /// Africa (Cape Town)
+    AfSouth1,
+    /// Asia Pacific (Hong Kong)
+    ApEast1,
+    /// Asia Pacific (Taipei)
+    ApEast2,
+    /// Asia Pacific (Tokyo)
+    ApNortheast1,
+    /// Asia Pacific (Seoul)
+    ApNortheast2,
+    /// Asia Pacific (Osaka)
+    ApNortheast3,
+    /// Asia Pacific (Mumbai)
+    ApSouth1,
+    /// Asia Pacific (Hyderabad)
+    ApSouth2,
+    /// Asia Pacific (Singapore)
+    ApSoutheast1,
+    /// Asia Pacific (Sydney)
+    ApSoutheast2,
+    /// Asia Pacific (Jakarta)
+    ApSoutheast3,
+    /// Asia Pacific (Melbourne)
+    ApSoutheast4,
+    /// Asia Pacific (Malaysia)
+    ApSoutheast5,
+    /// Asia Pacific (New Zealand)
+    ApSoutheast6,
+    /// Asia Pacific (Thailand)
+    ApSoutheast7,
+    /// Canada (Central)
+    CaCentral1,
+    /// Canada West (Calgary)
+    CaWest1,
+    /// Europe (Frankfurt)
+    EuCentral1,
+    /// Europe (Zurich)
+    EuCentral2,
+    /// Europe (Stockholm)
+    EuNorth1,
+    /// Europe (Milan)
+    EuSouth1,
+    /// Europe (Spain)
+    EuSouth2,
+    /// Europe (Ireland)
+    EuWest1,
+    /// Europe (London)
+    EuWest2,
+    /// Europe (Paris)
+    EuWest3,
+    /// Israel (Tel Aviv)
+    IlCentral1,
+    /// Middle East (UAE)
+    MeCentral1,
+    /// Middle East (Bahrain)
+    MeSouth1,
+    /// Mexico (Central)
+    MxCentral1,
+    /// South America (Sao Paulo)
+    SaEast1,
+    /// US East (N. Virginia)
+    #[default]
+    UsEast1,
+    /// US East (Ohio)
+    UsEast2,
+    /// US West (N. California)
+    UsWest1,
+    /// US West (Oregon)
+    UsWest2,
+    /// China (Beijing)
+    CnNorth1,
+    /// China (Ningxia)
+    CnNorthwest1,
+    /// AWS GovCloud (US-East)
+    UsGovEast1,
+    /// AWS GovCloud (US-West)
+    UsGovWest1,
+    /// US ISO East
+    UsIsoEast1,
+    /// US ISO WEST
+    UsIsoWest1,
+    /// US ISOB East (Ohio)
+    UsIsobEast1,
+    /// US ISOB West
+    UsIsobWest1,
+    /// EU ISOE West
+    EuIsoeWest1,
+    /// US ISOF EAST
+    UsIsofEast1,
+    /// US ISOF SOUTH
+    UsIsofSouth1,
+    /// AWS European Sovereign Cloud (Germany)
+    EuscDeEast1,
+    /// Europe
+    EuNorth2,
+}
+
+impl Region {
+    pub fn partition(&self) -> Partition {
+        use Region::*;
+
+        match self {
+            AfSouth1 | ApEast1 | ApEast2 | ApNortheast1 | ApNortheast2 | ApNortheast3
+            | ApSouth1 | ApSouth2 | ApSoutheast1 | ApSoutheast2 | ApSoutheast3 | ApSoutheast4
+            | ApSoutheast5 | ApSoutheast6 | ApSoutheast7 | CaCentral1 | CaWest1 | EuCentral1
+            | EuCentral2 | EuNorth1 | EuSouth1 | EuSouth2 | EuWest1 | EuWest2 | EuWest3
+            | IlCentral1 | MeCentral1 | MeSouth1 | MxCentral1 | SaEast1 | UsEast1 | UsEast2
+            | UsWest1 | UsWest2 | EuNorth2 => Partition::Aws,
+            CnNorth1 | CnNorthwest1 => Partition::AwsCn,
+            UsGovEast1 | UsGovWest1 => Partition::AwsUsGov,
+            UsIsoEast1 | UsIsoWest1 => Partition::AwsIso,
+            UsIsobEast1 | UsIsobWest1 => Partition::AwsIsoB,
+            EuIsoeWest1 => Partition::AwsIsoE,
+            UsIsofEast1 | UsIsofSouth1 => Partition::AwsIsoF,
+            EuscDeEast1 => Partition::AwsEusc,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        use Region::*;
+
+        match self {
+            AfSouth1 => "Africa (Cape Town)",
+            ApEast1 => "Asia Pacific (Hong Kong)",
+            ApEast2 => "Asia Pacific (Taipei)",
+            ApNortheast1 => "Asia Pacific (Tokyo)",
+            ApNortheast2 => "Asia Pacific (Seoul)",
+            ApNortheast3 => "Asia Pacific (Osaka)",
+            ApSouth1 => "Asia Pacific (Mumbai)",
+            ApSouth2 => "Asia Pacific (Hyderabad)",
+            ApSoutheast1 => "Asia Pacific (Singapore)",
+            ApSoutheast2 => "Asia Pacific (Sydney)",
+            ApSoutheast3 => "Asia Pacific (Jakarta)",
+            ApSoutheast4 => "Asia Pacific (Melbourne)",
+            ApSoutheast5 => "Asia Pacific (Malaysia)",
+            ApSoutheast6 => "Asia Pacific (New Zealand)",
+            ApSoutheast7 => "Asia Pacific (Thailand)",
+            CaCentral1 => "Canada (Central)",
+            CaWest1 => "Canada West (Calgary)",
+            EuCentral1 => "Europe (Frankfurt)",
+            EuCentral2 => "Europe (Zurich)",
+            EuNorth1 => "Europe (Stockholm)",
+            EuSouth1 => "Europe (Milan)",
+            EuSouth2 => "Europe (Spain)",
+            EuWest1 => "Europe (Ireland)",
+            EuWest2 => "Europe (London)",
+            EuWest3 => "Europe (Paris)",
+            IlCentral1 => "Israel (Tel Aviv)",
+            MeCentral1 => "Middle East (UAE)",
+            MeSouth1 => "Middle East (Bahrain)",
+            MxCentral1 => "Mexico (Central)",
+            SaEast1 => "South America (Sao Paulo)",
+            UsEast1 => "US East (N. Virginia)",
+            UsEast2 => "US East (Ohio)",
+            UsWest1 => "US West (N. California)",
+            UsWest2 => "US West (Oregon)",
+            CnNorth1 => "China (Beijing)",
+            CnNorthwest1 => "China (Ningxia)",
+            UsGovEast1 => "AWS GovCloud (US-East)",
+            UsGovWest1 => "AWS GovCloud (US-West)",
+            UsIsoEast1 => "US ISO East",
+            UsIsoWest1 => "US ISO WEST",
+            UsIsobEast1 => "US ISOB East (Ohio)",
+            UsIsobWest1 => "US ISOB West",
+            EuIsoeWest1 => "EU ISOE West",
+            UsIsofEast1 => "US ISOF EAST",
+            UsIsofSouth1 => "US ISOF SOUTH",
+            EuscDeEast1 => "AWS European Sovereign Cloud (Germany)",
+            EuNorth2 => "Europe",
+        }
+    }
+}
+
+impl AsRef<str> for Region {
+    fn as_ref(&self) -> &str {
+        use Region::*;
+
+        match self {
+            AfSouth1 => "af-south-1",
+            ApEast1 => "ap-east-1",
+            ApEast2 => "ap-east-2",
+            ApNortheast1 => "ap-northeast-1",
+            ApNortheast2 => "ap-northeast-2",
+            ApNortheast3 => "ap-northeast-3",
+            ApSouth1 => "ap-south-1",
+            ApSouth2 => "ap-south-2",
+            ApSoutheast1 => "ap-southeast-1",
+            ApSoutheast2 => "ap-southeast-2",
+            ApSoutheast3 => "ap-southeast-3",
+            ApSoutheast4 => "ap-southeast-4",
+            ApSoutheast5 => "ap-southeast-5",
+            ApSoutheast6 => "ap-southeast-6",
+            ApSoutheast7 => "ap-southeast-7",
+            CaCentral1 => "ca-central-1",
+            CaWest1 => "ca-west-1",
+            EuCentral1 => "eu-central-1",
+            EuCentral2 => "eu-central-2",
+            EuNorth1 => "eu-north-1",
+            EuSouth1 => "eu-south-1",
+            EuSouth2 => "eu-south-2",
+            EuWest1 => "eu-west-1",
+            EuWest2 => "eu-west-2",
+            EuWest3 => "eu-west-3",
+            IlCentral1 => "il-central-1",
+            MeCentral1 => "me-central-1",
+            MeSouth1 => "me-south-1",
+            MxCentral1 => "mx-central-1",
+            SaEast1 => "sa-east-1",
+            UsEast1 => "us-east-1",
+            UsEast2 => "us-east-2",
+            UsWest1 => "us-west-1",
+            UsWest2 => "us-west-2",
+            CnNorth1 => "cn-north-1",
+            CnNorthwest1 => "cn-northwest-1",
+            UsGovEast1 => "us-gov-east-1",
+            UsGovWest1 => "us-gov-west-1",
+            UsIsoEast1 => "us-iso-east-1",
+            UsIsoWest1 => "us-iso-west-1",
+            UsIsobEast1 => "us-isob-east-1",
+            UsIsobWest1 => "us-isob-west-1",
+            EuIsoeWest1 => "eu-isoe-west-1",
+            UsIsofEast1 => "us-isof-east-1",
+            UsIsofSouth1 => "us-isof-south-1",
+            EuscDeEast1 => "eusc-de-east-1",
+            EuNorth2 => "eu-north-2",
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = RegionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Region::*;
+
+        match s {
+            "af-south-1" => Ok(AfSouth1),
+            "ap-east-1" => Ok(ApEast1),
+            "ap-east-2" => Ok(ApEast2),
+            "ap-northeast-1" => Ok(ApNortheast1),
+            "ap-northeast-2" => Ok(ApNortheast2),
+            "ap-northeast-3" => Ok(ApNortheast3),
+            "ap-south-1" => Ok(ApSouth1),
+            "ap-south-2" => Ok(ApSouth2),
+            "ap-southeast-1" => Ok(ApSoutheast1),
+            "ap-southeast-2" => Ok(ApSoutheast2),
+            "ap-southeast-3" => Ok(ApSoutheast3),
+            "ap-southeast-4" => Ok(ApSoutheast4),
+            "ap-southeast-5" => Ok(ApSoutheast5),
+            "ap-southeast-6" => Ok(ApSoutheast6),
+            "ap-southeast-7" => Ok(ApSoutheast7),
+            "ca-central-1" => Ok(CaCentral1),
+            "ca-west-1" => Ok(CaWest1),
+            "eu-central-1" => Ok(EuCentral1),
+            "eu-central-2" => Ok(EuCentral2),
+            "eu-north-1" => Ok(EuNorth1),
+            "eu-south-1" => Ok(EuSouth1),
+            "eu-south-2" => Ok(EuSouth2),
+            "eu-west-1" => Ok(EuWest1),
+            "eu-west-2" => Ok(EuWest2),
+            "eu-west-3" => Ok(EuWest3),
+            "il-central-1" => Ok(IlCentral1),
+            "me-central-1" => Ok(MeCentral1),
+            "me-south-1" => Ok(MeSouth1),
+            "mx-central-1" => Ok(MxCentral1),
+            "sa-east-1" => Ok(SaEast1),
+            "us-east-1" => Ok(UsEast1),
+            "us-east-2" => Ok(UsEast2),
+            "us-west-1" => Ok(UsWest1),
+            "us-west-2" => Ok(UsWest2),
+            "cn-north-1" => Ok(CnNorth1),
+            "cn-northwest-1" => Ok(CnNorthwest1),
+            "us-gov-east-1" => Ok(UsGovEast1),
+            "us-gov-west-1" => Ok(UsGovWest1),
+            "us-iso-east-1" => Ok(UsIsoEast1),
+            "us-iso-west-1" => Ok(UsIsoWest1),
+            "us-isob-east-1" => Ok(UsIsobEast1),
+            "us-isob-west-1" => Ok(UsIsobWest1),
+            "eu-isoe-west-1" => Ok(EuIsoeWest1),
+            "us-isof-east-1" => Ok(UsIsofEast1),
+            "us-isof-south-1" => Ok(UsIsofSouth1),
+            "eusc-de-east-1" => Ok(EuscDeEast1),
+            "eu-north-2" => Ok(EuNorth2),
+            _ => Err(RegionError::DoesNotExist(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Checking whether the resource an ARN names exists.
+//!
+//! This crate only defines the interface; implementations bring their own
+//! AWS client and async runtime.
+
+use crate::Arn;
+use std::{collections::HashMap, future::Future, time::SystemTime};
+
+/// What a resolver knows about an existing resource.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResourceMetadata {
+    pub tags: HashMap<String, String>,
+    pub created: Option<SystemTime>,
+}
+
+pub trait ArnResolver {
+    type Error;
+
+    /// `Ok(None)` if the resource doesn't exist. Resolvers should return an
+    /// error, not `None`, for services they don't support.
+    fn resolve(
+        &self,
+        arn: &Arn,
+    ) -> impl Future<Output = Result<Option<ResourceMetadata>, Self::Error>> + Send;
+
+    fn exists(&self, arn: &Arn) -> impl Future<Output = Result<bool, Self::Error>> + Send
+    where
+        Self: Sync,
+    {
+        async move { Ok(self.resolve(arn).await?.is_some()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    struct Inventory(HashMap<Arn, ResourceMetadata>);
+
+    impl ArnResolver for Inventory {
+        type Error = std::convert::Infallible;
+
+        async fn resolve(&self, arn: &Arn) -> Result<Option<ResourceMetadata>, Self::Error> {
+            Ok(self.0.get(arn).cloned())
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolver() {
+        let bucket: Arn = "arn:aws:s3:::bucket".parse().unwrap();
+        let metadata = ResourceMetadata {
+            tags: HashMap::from([("team".to_string(), "data".to_string())]),
+            created: Some(SystemTime::UNIX_EPOCH),
+        };
+        let inventory = Inventory(HashMap::from([(bucket, metadata.clone())]));
+
+        assert_eq!(block_on(inventory.resolve(&bucket)), Ok(Some(metadata)));
+        assert_eq!(block_on(inventory.exists(&bucket)), Ok(true));
+        assert_eq!(block_on(inventory.exists(&Arn::ANY)), Ok(false));
+    }
+}
@@ -0,0 +1,217 @@
+//! Route 53 hosted zone and health check ARNs, which have neither region
+//! nor account.
+
+use crate::{
+    Arn, ArnField, Component, Partition,
+    typed::{is_uuid, resource, resource_of},
+    validate::ValidationError,
+};
+
+const SERVICE: &str = "route53";
+
+fn new_arn(partition: Partition, resource: &str) -> Result<Arn, ValidationError> {
+    let mut arn = Arn {
+        partition,
+        ..Arn::default()
+    };
+    arn.set_service(SERVICE)?;
+    arn.set_resource_id(resource)?;
+    Ok(arn)
+}
+
+fn check_no_region_or_account(arn: &Arn) -> Result<(), ValidationError> {
+    if let Component::Value(_) = arn.region {
+        return Err(ValidationError::invalid(
+            ArnField::Region,
+            "Route 53 ARNs have no region",
+        ));
+    }
+    if let Component::Value(_) = arn.account {
+        return Err(ValidationError::invalid(
+            ArnField::Account,
+            "Route 53 ARNs have no account",
+        ));
+    }
+    Ok(())
+}
+
+fn check_zone_id(id: &str) -> Result<(), ValidationError> {
+    if id.is_empty()
+        || !id
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        return Err(ValidationError::invalid(
+            ArnField::Resource,
+            format!("'{id}' is not a hosted zone ID"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_health_check_id(id: &str) -> Result<(), ValidationError> {
+    if !is_uuid(id) {
+        return Err(ValidationError::invalid(
+            ArnField::Resource,
+            format!("'{id}' is not a health check ID"),
+        ));
+    }
+    Ok(())
+}
+
+/// `arn:aws:route53:::hostedzone/{id}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct HostedZoneArn(Arn);
+
+typed_arn!(HostedZoneArn);
+
+impl HostedZoneArn {
+    /// From a bare ID (`Z1D633PJN98FT9`) or the `/hostedzone/Z1D633PJN98FT9`
+    /// form the Route 53 API returns.
+    pub fn from_id(partition: Partition, id: &str) -> Result<Self, ValidationError> {
+        let id = id.strip_prefix("/hostedzone/").unwrap_or(id);
+        check_zone_id(id)?;
+        Ok(HostedZoneArn(new_arn(
+            partition,
+            &format!("hostedzone/{id}"),
+        )?))
+    }
+
+    /// Accepts either an ARN or any ID form [`from_id`](Self::from_id)
+    /// accepts, in the `aws` partition.
+    pub fn from_id_or_arn(value: &str) -> Result<Self, ValidationError> {
+        if value.starts_with("arn:") {
+            value.parse()
+        } else {
+            Self::from_id(Partition::Aws, value)
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &resource_of(&self.0)["hostedzone/".len()..]
+    }
+}
+
+impl TryFrom<Arn> for HostedZoneArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        check_zone_id(resource(&arn, SERVICE, "hostedzone/")?)?;
+        check_no_region_or_account(&arn)?;
+        Ok(HostedZoneArn(arn))
+    }
+}
+
+/// `arn:aws:route53:::healthcheck/{id}`
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub struct HealthCheckArn(Arn);
+
+typed_arn!(HealthCheckArn);
+
+impl HealthCheckArn {
+    pub fn from_id(partition: Partition, id: &str) -> Result<Self, ValidationError> {
+        check_health_check_id(id)?;
+        Ok(HealthCheckArn(new_arn(
+            partition,
+            &format!("healthcheck/{id}"),
+        )?))
+    }
+
+    pub fn id(&self) -> &str {
+        &resource_of(&self.0)["healthcheck/".len()..]
+    }
+}
+
+impl TryFrom<Arn> for HealthCheckArn {
+    type Error = ValidationError;
+
+    fn try_from(arn: Arn) -> Result<Self, Self::Error> {
+        check_health_check_id(resource(&arn, SERVICE, "healthcheck/")?)?;
+        check_no_region_or_account(&arn)?;
+        Ok(HealthCheckArn(arn))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hosted_zone() {
+        let arn = "arn:aws:route53:::hostedzone/Z1D633PJN98FT9";
+        let zone: HostedZoneArn = arn.parse().unwrap();
+        assert_eq!(zone.id(), "Z1D633PJN98FT9");
+        assert_eq!(
+            HostedZoneArn::from_id(Partition::Aws, "Z1D633PJN98FT9").unwrap(),
+            zone
+        );
+        assert_eq!(
+            HostedZoneArn::from_id(Partition::Aws, "/hostedzone/Z1D633PJN98FT9").unwrap(),
+            zone
+        );
+        assert_eq!(HostedZoneArn::from_id_or_arn(arn).unwrap(), zone);
+        assert_eq!(
+            HostedZoneArn::from_id_or_arn("Z1D633PJN98FT9").unwrap(),
+            zone
+        );
+        assert_eq!(
+            HostedZoneArn::from_id(Partition::AwsCn, "Z1")
+                .unwrap()
+                .to_string(),
+            "arn:aws-cn:route53:::hostedzone/Z1"
+        );
+
+        assert!(HostedZoneArn::from_id(Partition::Aws, "z1-bad").is_err());
+        assert!(
+            "arn:aws:route53:::hostedzone/"
+                .parse::<HostedZoneArn>()
+                .is_err()
+        );
+        assert!(
+            "arn:aws:route53:::healthcheck/Z1"
+                .parse::<HostedZoneArn>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rejects_region_and_account() {
+        for arn in [
+            "arn:aws:route53:us-east-1::hostedzone/Z1",
+            "arn:aws:route53::123456789012:hostedzone/Z1",
+        ] {
+            assert!(
+                matches!(
+                    arn.parse::<HostedZoneArn>(),
+                    Err(ValidationError::Invalid { .. })
+                ),
+                "{arn}"
+            );
+        }
+        let id = "abcdef11-2222-3333-4444-555555fedcba";
+        let err = format!("arn:aws:route53::123456789012:healthcheck/{id}")
+            .parse::<HealthCheckArn>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid account: Route 53 ARNs have no account"
+        );
+    }
+
+    #[test]
+    fn test_health_check() {
+        let id = "abcdef11-2222-3333-4444-555555fedcba";
+        let check = HealthCheckArn::from_id(Partition::Aws, id).unwrap();
+        assert_eq!(
+            check.to_string(),
+            format!("arn:aws:route53:::healthcheck/{id}")
+        );
+        assert_eq!(check.id(), id);
+        assert_eq!(check.to_string().parse::<HealthCheckArn>().unwrap(), check);
+        assert!(HealthCheckArn::from_id(Partition::Aws, "abcdef11").is_err());
+        assert!(
+            HealthCheckArn::from_id(Partition::Aws, "zzzzzzzz-2222-3333-4444-555555fedcba")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Adapters for `#[serde(with = ...)]` that (de)serialize a `Vec<Arn>` as a
+//! single delimited string, as found in environment variables and legacy
+//! configs:
+//!
+//! ```
+//! use arn::Arn;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(with = "arn::serde::comma_separated")]
+//!     topics: Vec<Arn>,
+//! }
+//!
+//! let json = r#"{"topics": "arn:aws:sns:us-east-1:123456789012:a, arn:aws:sns:us-east-1:123456789012:b"}"#;
+//! let config: Config = serde_json::from_str(json).unwrap();
+//! assert_eq!(config.topics.len(), 2);
+//! ```
+//!
+//! Empty items are skipped and items are trimmed. Deserialization reports
+//! every item that fails to parse, by its 0-based position in the input,
+//! empty items included.
+
+use crate::Arn;
+use ::serde::{Deserialize, Deserializer, Serializer, de};
+use std::{borrow::Cow, fmt};
+
+struct Joined<'a>(&'a [Arn], &'a str);
+
+impl fmt::Display for Joined<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arn) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.1)?;
+            }
+            write!(f, "{arn}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_items<'a, E: de::Error>(items: impl Iterator<Item = &'a str>) -> Result<Vec<Arn>, E> {
+    let mut arns = Vec::new();
+    let mut errors = Vec::new();
+    for (i, item) in items.map(str::trim).enumerate() {
+        if item.is_empty() {
+            continue;
+        }
+        match item.parse() {
+            Ok(arn) => arns.push(arn),
+            Err(err) => errors.push(format!("item {i} ({item:?}): {err}")),
+        }
+    }
+    if errors.is_empty() {
+        Ok(arns)
+    } else {
+        Err(E::custom(errors.join("; ")))
+    }
+}
+
+/// `"arn:...,arn:..."`; serializes without spaces.
+pub mod comma_separated {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(arns: &[Arn], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Joined(arns, ","))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Arn>, D::Error> {
+        let value = Cow::<str>::deserialize(deserializer)?;
+        parse_items(value.split(','))
+    }
+}
+
+/// `"arn:... arn:..."`, split on any whitespace; serializes with single
+/// spaces.
+pub mod whitespace_separated {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(arns: &[Arn], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Joined(arns, " "))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Arn>, D::Error> {
+        let value = Cow::<str>::deserialize(deserializer)?;
+        parse_items(value.split_whitespace())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Arn;
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "super::comma_separated")]
+        comma: Vec<Arn>,
+        #[serde(with = "super::whitespace_separated")]
+        space: Vec<Arn>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = r#"{"comma":"arn:aws:s3:::a, arn:aws:s3:::b,","space":"arn:aws:s3:::c\n\tarn:aws:s3:::d"}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.comma.len(), 2);
+        assert_eq!(config.space[1].to_string(), "arn:aws:s3:::d");
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            r#"{"comma":"arn:aws:s3:::a,arn:aws:s3:::b","space":"arn:aws:s3:::c arn:aws:s3:::d"}"#
+        );
+
+        let empty: Config = serde_json::from_str(r#"{"comma":"","space":" "}"#).unwrap();
+        assert!(empty.comma.is_empty() && empty.space.is_empty());
+    }
+
+    #[test]
+    fn test_item_errors() {
+        let json = r#"{"comma":"arn:aws:s3:::a,arn:aws,arn:aws:s3:mars-1::b","space":""}"#;
+        let err = serde_json::from_str::<Config>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("item 1 (\"arn:aws\"): "), "{err}");
+        assert!(
+            err.contains("; item 2 (\"arn:aws:s3:mars-1::b\"): "),
+            "{err}"
+        );
+    }
+}
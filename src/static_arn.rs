@@ -0,0 +1,162 @@
+use crate::{Arn, Component, Partition, Region};
+use arrayvec::ArrayString;
+use std::{ops::Deref, sync::OnceLock};
+
+/// A wrapper that lets well-known ARNs live in `static` tables. It is not a
+/// const [`Arn`]: `ArrayString` has no const constructor from a `&str`, so
+/// the components are kept as `&'static str` and the `Arn` is built, once,
+/// on first use. Only the lengths are checked at compile time.
+///
+/// ```
+/// use arn::{Component, Partition, StaticArn};
+///
+/// static AUDIT_BUCKET: StaticArn = StaticArn::new(
+///     Partition::Aws,
+///     Component::Value("s3"),
+///     Component::None,
+///     Component::None,
+///     Component::Value("audit-logs"),
+/// );
+/// assert_eq!(AUDIT_BUCKET.to_string(), "arn:aws:s3:::audit-logs");
+/// ```
+#[derive(Debug)]
+pub struct StaticArn {
+    partition: Partition,
+    service: Component<&'static str>,
+    region: Component<Region>,
+    account: Component<&'static str>,
+    resource_id: Component<&'static str>,
+    arn: OnceLock<Arn>,
+}
+
+const fn fits(component: Component<&str>, max: usize) -> bool {
+    match component {
+        Component::Value(value) => value.len() <= max,
+        _ => true,
+    }
+}
+
+fn component<const N: usize>(component: Component<&str>) -> Component<ArrayString<N>> {
+    match component {
+        Component::Value("") | Component::None => Component::None,
+        Component::Any => Component::Any,
+        // Lengths are checked in `StaticArn::new`.
+        Component::Value(value) => Component::Value(ArrayString::from(value).unwrap()),
+    }
+}
+
+impl StaticArn {
+    /// `Component::Value("")` is an empty component, as in parsing. Panics
+    /// (at compile time, in a `static`) on over-long components.
+    pub const fn new(
+        partition: Partition,
+        service: Component<&'static str>,
+        region: Component<Region>,
+        account: Component<&'static str>,
+        resource_id: Component<&'static str>,
+    ) -> Self {
+        assert!(fits(service, 32), "service too long");
+        assert!(fits(account, 12), "account too long");
+        assert!(fits(resource_id, 64), "resource ID too long");
+        StaticArn {
+            partition,
+            service,
+            region,
+            account,
+            resource_id,
+            arn: OnceLock::new(),
+        }
+    }
+
+    pub fn arn(&self) -> &Arn {
+        self.arn.get_or_init(|| Arn {
+            partition: self.partition,
+            service: component(self.service),
+            region: self.region,
+            account: component(self.account),
+            resource_id: component(self.resource_id),
+        })
+    }
+}
+
+impl Deref for StaticArn {
+    type Target = Arn;
+
+    fn deref(&self) -> &Arn {
+        self.arn()
+    }
+}
+
+impl From<&StaticArn> for Arn {
+    fn from(arn: &StaticArn) -> Arn {
+        *arn.arn()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    static TABLE: [StaticArn; 2] = [
+        StaticArn::new(
+            Partition::Aws,
+            Component::Value("iam"),
+            Component::None,
+            Component::Value("123456789012"),
+            Component::Value("role/admin"),
+        ),
+        StaticArn::new(
+            Partition::AwsCn,
+            Component::Value("sqs"),
+            Component::Value(Region::CnNorth1),
+            Component::Value("123456789012"),
+            Component::Any,
+        ),
+    ];
+
+    #[test]
+    fn test_static_arns() {
+        assert_eq!(
+            *TABLE[0],
+            "arn:aws:iam::123456789012:role/admin".parse().unwrap()
+        );
+        assert_eq!(
+            Arn::from(&TABLE[1]),
+            Arn {
+                resource_id: Component::Any,
+                ..Arn::from_str("arn:aws-cn:sqs:cn-north-1:123456789012:x").unwrap()
+            }
+        );
+        let any = StaticArn::new(
+            Partition::Aws,
+            Component::Any,
+            Component::Any,
+            Component::Any,
+            Component::Any,
+        );
+        assert_eq!(*any, Arn::ANY);
+        let empty = StaticArn::new(
+            Partition::Aws,
+            Component::Value(""),
+            Component::None,
+            Component::None,
+            Component::None,
+        );
+        assert_eq!(empty.arn().service, Component::None);
+        assert_eq!(empty.to_string(), "arn:aws::::");
+    }
+
+    #[test]
+    #[should_panic(expected = "resource ID too long")]
+    fn test_too_long() {
+        let long: &'static str = "x".repeat(65).leak();
+        StaticArn::new(
+            Partition::Aws,
+            Component::Value("s3"),
+            Component::None,
+            Component::None,
+            Component::Value(long),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Shared plumbing for service-specific ARN wrappers such as
+//! [`EventRuleArn`](crate::eventbridge::EventRuleArn).
+
+use crate::{Arn, ArnField, Component, validate::ValidationError};
+
+/// The resource of `arn` after `prefix`, checking that `arn` is for
+/// `service`.
+pub(crate) fn resource<'a>(
+    arn: &'a Arn,
+    service: &str,
+    prefix: &str,
+) -> Result<&'a str, ValidationError> {
+    if arn.get(ArnField::Service) != Component::Value(service) {
+        return Err(ValidationError::invalid(
+            ArnField::Service,
+            format!("expected {service}"),
+        ));
+    }
+    match arn.get(ArnField::Resource) {
+        Component::Value(resource) => resource.strip_prefix(prefix).ok_or_else(|| {
+            ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{resource}' does not start with '{prefix}'"),
+            )
+        }),
+        _ => Err(ValidationError::invalid(
+            ArnField::Resource,
+            format!("expected {prefix}..."),
+        )),
+    }
+}
+
+/// The resource of an ARN already checked by [`resource`], or `""`.
+pub(crate) fn resource_of(arn: &Arn) -> &str {
+    match arn.get(ArnField::Resource) {
+        Component::Value(resource) => resource,
+        _ => "",
+    }
+}
+
+/// Whether `id` is a UUID such as `12345678-1234-1234-1234-123456789012`.
+pub(crate) fn is_uuid(id: &str) -> bool {
+    id.split('-').map(str::len).eq([8, 4, 4, 4, 12])
+        && id.bytes().all(|b| b.is_ascii_hexdigit() || b == b'-')
+}
+
+/// Conversions shared by every `struct Typed(Arn)` wrapper; the wrapper
+/// provides its own `TryFrom<Arn>`.
+macro_rules! typed_arn {
+    ($name:ident) => {
+        impl $name {
+            pub fn arn(&self) -> &$crate::Arn {
+                &self.0
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::validate::ValidationError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                value.parse::<$crate::Arn>()?.try_into()
+            }
+        }
+
+        impl From<$name> for $crate::Arn {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $crate::Arn;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
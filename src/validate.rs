@@ -0,0 +1,281 @@
+//! Service-specific validation on top of parsing.
+//!
+//! Parsing only checks what every ARN has in common. An [`ArnValidator`]
+//! runs extra [`Validator`]s keyed by service, e.g. to enforce naming
+//! conventions:
+//!
+//! ```
+//! use arn::{Arn, ArnField, Component};
+//! use arn::validate::{ArnValidator, ValidationError};
+//!
+//! let mut validator = ArnValidator::with_builtins();
+//! validator.register("sqs", |arn: &Arn| match arn.get(ArnField::Resource) {
+//!     Component::Value(name) if !name.starts_with("acme-") => Err(
+//!         ValidationError::invalid(ArnField::Resource, "queues must start with acme-"),
+//!     ),
+//!     _ => Ok(()),
+//! });
+//! assert!(validator.parse("arn:aws:sqs:us-east-1:123456789012:acme-jobs").is_ok());
+//! assert!(validator.parse("arn:aws:sqs:us-east-1:123456789012:jobs").is_err());
+//! ```
+
+use crate::{Arn, ArnField, ArnParseError, Component, global};
+use std::collections::HashMap;
+use thiserror::Error;
+
+mod iam;
+pub use iam::IamName;
+mod lambda;
+pub use lambda::LambdaFunction;
+mod s3;
+pub use s3::S3BucketName;
+
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error(transparent)]
+    Parse(#[from] ArnParseError),
+    #[error("Invalid {}: {message}", field.as_ref())]
+    Invalid { field: ArnField, message: String },
+}
+
+impl ValidationError {
+    pub fn invalid(field: ArnField, message: impl Into<String>) -> Self {
+        ValidationError::Invalid {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+pub trait Validator: Send + Sync {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError>;
+}
+
+impl<F: Fn(&Arn) -> Result<(), ValidationError> + Send + Sync> Validator for F {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        self(arn)
+    }
+}
+
+/// Rejects a missing or unexpected region or account for known services;
+/// see [`Arn::has_expected_shape`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Shape;
+
+impl Validator for Shape {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        let Component::Value(service) = arn.get(ArnField::Service) else {
+            return Ok(());
+        };
+        match arn.region {
+            Component::Value(_) if global::is_global_service(service) => {
+                return Err(ValidationError::invalid(
+                    ArnField::Region,
+                    format!("{service} ARNs have no region"),
+                ));
+            }
+            Component::None if global::requires_region(service) => {
+                return Err(ValidationError::invalid(ArnField::Region, "missing"));
+            }
+            _ => {}
+        }
+        if arn.account == Component::None && global::requires_account(service) {
+            return Err(ValidationError::invalid(ArnField::Account, "missing"));
+        }
+        Ok(())
+    }
+}
+
+/// Whether `account` is a 12-digit AWS account ID.
+pub(crate) fn is_account_id(account: &str) -> bool {
+    account.len() == 12 && account.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Requires the account, when present, to be a 12-digit ID or `aws` (as in
+/// AWS managed policies).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AccountId;
+
+impl Validator for AccountId {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        match arn.get(ArnField::Account) {
+            Component::Value(account) if account != "aws" && !is_account_id(account) => {
+                Err(ValidationError::invalid(
+                    ArnField::Account,
+                    format!("'{account}' is not a 12-digit account ID"),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A registry of [`Validator`]s, run for every ARN or only for ARNs of a
+/// given service.
+#[derive(Default)]
+pub struct ArnValidator {
+    all: Vec<Box<dyn Validator>>,
+    by_service: HashMap<String, Vec<Box<dyn Validator>>>,
+}
+
+impl ArnValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with the built-in validators registered.
+    pub fn with_builtins() -> Self {
+        let mut validator = Self::new();
+        validator
+            .register_all(Shape)
+            .register_all(AccountId)
+            .register("iam", IamName)
+            .register("lambda", LambdaFunction)
+            .register("s3", S3BucketName);
+        validator
+    }
+
+    /// Runs `validator` for ARNs whose service is `service`.
+    pub fn register(
+        &mut self,
+        service: impl Into<String>,
+        validator: impl Validator + 'static,
+    ) -> &mut Self {
+        self.by_service
+            .entry(service.into())
+            .or_default()
+            .push(Box::new(validator));
+        self
+    }
+
+    /// Runs `validator` for every ARN.
+    pub fn register_all(&mut self, validator: impl Validator + 'static) -> &mut Self {
+        self.all.push(Box::new(validator));
+        self
+    }
+
+    fn validators<'a>(&'a self, arn: &Arn) -> impl Iterator<Item = &'a dyn Validator> {
+        let service = match arn.get(ArnField::Service) {
+            Component::Value(service) => self.by_service.get(service),
+            _ => None,
+        };
+        self.all
+            .iter()
+            .chain(service.into_iter().flatten())
+            .map(|v| v.as_ref())
+    }
+
+    /// The first error from the registered validators, in registration order.
+    pub fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        self.validators(arn).try_for_each(|v| v.validate(arn))
+    }
+
+    /// Every error from the registered validators.
+    pub fn validate_all(&self, arn: &Arn) -> Vec<ValidationError> {
+        self.validators(arn)
+            .filter_map(|v| v.validate(arn).err())
+            .collect()
+    }
+
+    /// Parses `input` and validates the result.
+    pub fn parse(&self, input: &str) -> Result<Arn, ValidationError> {
+        let arn = input.parse()?;
+        self.validate(&arn)?;
+        Ok(arn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arn(s: &str) -> Arn {
+        s.parse().unwrap()
+    }
+
+    fn field(result: Result<(), ValidationError>) -> Option<ArnField> {
+        match result {
+            Err(ValidationError::Invalid { field, .. }) => Some(field),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_builtins() {
+        let validator = ArnValidator::with_builtins();
+        assert!(
+            validator
+                .validate(&arn("arn:aws:iam::123456789012:role/r"))
+                .is_ok()
+        );
+        assert!(
+            validator
+                .validate(&arn("arn:aws:iam::aws:policy/ReadOnlyAccess"))
+                .is_ok()
+        );
+        assert!(validator.validate(&arn("arn:aws:s3:::bucket")).is_ok());
+        assert_eq!(
+            field(validator.validate(&arn("arn:aws:s3:::Bucket"))),
+            Some(ArnField::Resource)
+        );
+        assert_eq!(
+            field(validator.validate(&arn("arn:aws:iam:us-east-1:123456789012:role/r"))),
+            Some(ArnField::Region)
+        );
+        assert_eq!(
+            field(validator.validate(&arn("arn:aws:sqs::123456789012:queue"))),
+            Some(ArnField::Region)
+        );
+        assert_eq!(
+            field(validator.validate(&arn("arn:aws:sqs:us-east-1::queue"))),
+            Some(ArnField::Account)
+        );
+        assert_eq!(
+            field(validator.validate(&arn("arn:aws:sqs:us-east-1:12345:queue"))),
+            Some(ArnField::Account)
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let mut validator = ArnValidator::new();
+        validator.register("lambda", |arn: &Arn| {
+            if arn.qualifier().is_some() {
+                Err(ValidationError::invalid(ArnField::Resource, "qualified"))
+            } else {
+                Ok(())
+            }
+        });
+        assert!(
+            validator
+                .validate(&arn("arn:aws:sns:::function:f:1"))
+                .is_ok()
+        );
+        assert!(
+            validator
+                .validate(&arn("arn:aws:lambda:::function:f:1"))
+                .is_err()
+        );
+
+        validator.register_all(AccountId);
+        let bad = arn("arn:aws:lambda::1:function:f:1");
+        assert_eq!(validator.validate_all(&bad).len(), 2);
+        assert_eq!(
+            validator.validate(&bad).unwrap_err().to_string(),
+            "Invalid account: '1' is not a 12-digit account ID"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let validator = ArnValidator::with_builtins();
+        assert!(matches!(
+            validator.parse("arn:aws"),
+            Err(ValidationError::Parse(ArnParseError::InvalidFormat(2)))
+        ));
+        assert!(matches!(
+            validator.parse("arn:aws:sqs::123456789012:queue"),
+            Err(ValidationError::Invalid { .. })
+        ));
+    }
+}
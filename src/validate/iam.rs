@@ -0,0 +1,128 @@
+use super::{ValidationError, Validator};
+use crate::{Arn, ArnField, Component};
+
+const NAME_LIMITS: &[(&str, usize)] = &[
+    ("group", 128),
+    ("instance-profile", 128),
+    ("policy", 128),
+    ("role", 64),
+    ("user", 64),
+];
+
+fn invalid(message: String) -> Result<(), ValidationError> {
+    Err(ValidationError::invalid(ArnField::Resource, message))
+}
+
+/// Enforces IAM name and path rules on `role/`, `user/`, `group/`,
+/// `policy/` and `instance-profile/` ARNs. Other resource types and
+/// wildcards are skipped.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IamName;
+
+impl IamName {
+    /// Checks the name of an IAM resource of type `kind`, e.g. `role`.
+    pub fn check_name(kind: &str, name: &str) -> Result<(), ValidationError> {
+        let max = NAME_LIMITS
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(128, |(_, max)| *max);
+        if name.is_empty() || name.len() > max {
+            return invalid(format!(
+                "{kind} name '{name}' must be 1 to {max} characters"
+            ));
+        }
+        match name
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || "+=,.@_-".contains(*c)))
+        {
+            Some(c) => invalid(format!("{kind} name '{name}' contains '{c}'")),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks an IAM path such as `/division_abc/subdivision_xyz/`.
+    pub fn check_path(path: &str) -> Result<(), ValidationError> {
+        if path.len() > 512 {
+            return invalid(format!("path '{path}' is longer than 512 characters"));
+        }
+        if !path.starts_with('/') || !path.ends_with('/') {
+            return invalid(format!("path '{path}' must start and end with '/'"));
+        }
+        if let Some(c) = path.chars().find(|c| !('!'..='~').contains(c)) {
+            return invalid(format!("path '{path}' contains '{c}'"));
+        }
+        if path.len() > 1 && path[1..path.len() - 1].split('/').any(str::is_empty) {
+            return invalid(format!("path '{path}' has an empty segment"));
+        }
+        Ok(())
+    }
+}
+
+impl Validator for IamName {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        if arn.get(ArnField::Service) != Component::Value("iam") {
+            return Ok(());
+        }
+        let Component::Value(resource) = arn.get(ArnField::Resource) else {
+            return Ok(());
+        };
+        let Some((kind, rest)) = resource.split_once('/') else {
+            return Ok(());
+        };
+        if !NAME_LIMITS.iter().any(|(k, _)| *k == kind) || rest.contains(['*', '?']) {
+            return Ok(());
+        }
+        let (path, name) = match rest.rsplit_once('/') {
+            Some((path, name)) => (format!("/{path}/"), name),
+            None => ("/".to_string(), rest),
+        };
+        Self::check_path(&path)?;
+        Self::check_name(kind, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(resource: &str) -> Result<(), ValidationError> {
+        let arn = format!("arn:aws:iam::123456789012:{resource}");
+        IamName.validate(&arn.parse().unwrap())
+    }
+
+    #[test]
+    fn test_validate() {
+        for resource in [
+            "role/my-role",
+            "role/service-role/my+role=1,2.3@x",
+            "user/division/team/alice",
+            "policy/ReadOnly",
+            "role/dev-*",
+            "mfa/alice",
+            "root",
+        ] {
+            assert!(validate(resource).is_ok(), "{resource}");
+        }
+        for resource in ["role/", "role/my role", "role/a//b", "user/caf\u{e9}"] {
+            assert!(validate(resource).is_err(), "{resource}");
+        }
+        assert!(validate(&format!("group/{}", "g".repeat(58))).is_ok());
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(IamName::check_path("/").is_ok());
+        assert!(IamName::check_path("/a/b/").is_ok());
+        assert!(IamName::check_path("a/").is_err());
+        assert!(IamName::check_path("/a").is_err());
+        assert!(IamName::check_path("/a b/").is_err());
+        assert!(IamName::check_path("//").is_err());
+        assert!(IamName::check_name("role", &"r".repeat(64)).is_ok());
+        assert!(IamName::check_name("role", &"r".repeat(65)).is_err());
+        assert!(IamName::check_name("policy", &"p".repeat(128)).is_ok());
+        assert_eq!(
+            IamName::check_name("role", "a/b").unwrap_err().to_string(),
+            "Invalid resource: role name 'a/b' contains '/'"
+        );
+    }
+}
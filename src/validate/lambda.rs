@@ -0,0 +1,128 @@
+use super::{ValidationError, Validator};
+use crate::{Arn, ArnField, Component};
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Enforces Lambda function name and qualifier rules on `function:` ARNs:
+/// names are 1 to 64 letters, digits, `-` or `_`, and qualifiers are
+/// `$LATEST`, a version number, or an alias name. Wildcards are skipped.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LambdaFunction;
+
+impl LambdaFunction {
+    /// Checks a function name on its own.
+    pub fn check_name(name: &str) -> Result<(), ValidationError> {
+        if name.is_empty() || name.len() > 64 {
+            return Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("function name '{name}' must be 1 to 64 characters"),
+            ));
+        }
+        match name.chars().find(|c| !is_name_char(*c)) {
+            Some(c) => Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("function name '{name}' contains '{c}'"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a version or alias qualifier on its own.
+    pub fn check_qualifier(qualifier: &str) -> Result<(), ValidationError> {
+        let version = qualifier == "$LATEST"
+            || (!qualifier.is_empty() && qualifier.bytes().all(|b| b.is_ascii_digit()));
+        let alias = (1..=128).contains(&qualifier.len()) && qualifier.chars().all(is_name_char);
+        if version || alias {
+            Ok(())
+        } else {
+            Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{qualifier}' is not a version or alias name"),
+            ))
+        }
+    }
+}
+
+impl Validator for LambdaFunction {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        if arn.get(ArnField::Service) != Component::Value("lambda") {
+            return Ok(());
+        }
+        let Component::Value(resource) = arn.get(ArnField::Resource) else {
+            return Ok(());
+        };
+        let Some(function) = resource.strip_prefix("function:") else {
+            return Ok(());
+        };
+        if function.contains(['*', '?']) {
+            return Ok(());
+        }
+        let mut parts = function.split(':');
+        Self::check_name(parts.next().unwrap())?;
+        if let Some(qualifier) = parts.next() {
+            Self::check_qualifier(qualifier)?;
+        }
+        match parts.next() {
+            Some(_) => Err(ValidationError::invalid(
+                ArnField::Resource,
+                format!("'{function}' has more than one qualifier"),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(s: &str) -> Result<(), ValidationError> {
+        LambdaFunction.validate(&s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_validate() {
+        let prefix = "arn:aws:lambda:us-east-1:123456789012";
+        for resource in [
+            "function:my_function-1",
+            "function:f:$LATEST",
+            "function:f:42",
+            "function:f:live",
+            "function:f-*",
+            "layer:my-layer:1",
+        ] {
+            assert!(
+                validate(&format!("{prefix}:{resource}")).is_ok(),
+                "{resource}"
+            );
+        }
+        for resource in [
+            "function:",
+            "function:my/function",
+            "function:f:",
+            "function:f:$PREVIOUS",
+            "function:f:1:2",
+        ] {
+            assert!(
+                validate(&format!("{prefix}:{resource}")).is_err(),
+                "{resource}"
+            );
+        }
+        assert_eq!(
+            validate(&format!("{prefix}:function:my/function"))
+                .unwrap_err()
+                .to_string(),
+            "Invalid resource: function name 'my/function' contains '/'"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(LambdaFunction::check_name(&"f".repeat(64)).is_ok());
+        assert!(LambdaFunction::check_name(&"f".repeat(65)).is_err());
+        assert!(LambdaFunction::check_qualifier("prod-2").is_ok());
+        assert!(LambdaFunction::check_qualifier("").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use super::{ValidationError, Validator};
+use crate::{Arn, ArnField, Component};
+
+const RESERVED_PREFIXES: &[&str] = &["xn--", "sthree-", "amzn-s3-demo-"];
+const RESERVED_SUFFIXES: &[&str] = &["-s3alias", "--ol-s3", ".mrap", "--x-s3"];
+
+/// Enforces S3 general purpose bucket naming rules on bucket ARNs
+/// (`arn:aws:s3:::bucket` and `arn:aws:s3:::bucket/key`). S3 ARNs with a
+/// region or account, such as access points, and wildcard names are skipped.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct S3BucketName;
+
+impl S3BucketName {
+    /// Checks a bucket name on its own.
+    pub fn check(name: &str) -> Result<(), ValidationError> {
+        let invalid = |message: String| Err(ValidationError::invalid(ArnField::Resource, message));
+
+        if !(3..=63).contains(&name.len()) {
+            return invalid(format!("bucket name '{name}' must be 3 to 63 characters"));
+        }
+        if let Some(c) = name
+            .chars()
+            .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '.' || *c == '-'))
+        {
+            return invalid(format!("bucket name '{name}' contains '{c}'"));
+        }
+        let alphanumeric = |b: Option<&u8>| b.is_some_and(|b| b.is_ascii_alphanumeric());
+        for label in name.split('.') {
+            if !alphanumeric(label.as_bytes().first()) || !alphanumeric(label.as_bytes().last()) {
+                return invalid(format!(
+                    "bucket name '{name}' must have labels starting and ending with a letter or digit"
+                ));
+            }
+        }
+        let mut octets = name.split('.');
+        if octets.clone().count() == 4 && octets.all(|o| o.parse::<u8>().is_ok()) {
+            return invalid(format!(
+                "bucket name '{name}' is formatted as an IP address"
+            ));
+        }
+        if let Some(prefix) = RESERVED_PREFIXES.iter().find(|p| name.starts_with(*p)) {
+            return invalid(format!(
+                "bucket name '{name}' has reserved prefix '{prefix}'"
+            ));
+        }
+        if let Some(suffix) = RESERVED_SUFFIXES.iter().find(|s| name.ends_with(*s)) {
+            return invalid(format!(
+                "bucket name '{name}' has reserved suffix '{suffix}'"
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Validator for S3BucketName {
+    fn validate(&self, arn: &Arn) -> Result<(), ValidationError> {
+        if arn.get(ArnField::Service) != Component::Value("s3")
+            || arn.region != Component::None
+            || arn.account != Component::None
+        {
+            return Ok(());
+        }
+        let Component::Value(resource) = arn.get(ArnField::Resource) else {
+            return Ok(());
+        };
+        let bucket = resource.split('/').next().unwrap();
+        if bucket.contains(['*', '?']) {
+            return Ok(());
+        }
+        Self::check(bucket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        for name in [
+            "abc",
+            "my-bucket",
+            "logs.example.com",
+            "1-2",
+            &"a".repeat(63),
+        ] {
+            assert!(S3BucketName::check(name).is_ok(), "{name}");
+        }
+        for name in [
+            "ab",
+            &"a".repeat(64),
+            "My-Bucket",
+            "my_bucket",
+            "-bucket",
+            "bucket-",
+            "my..bucket",
+            "my.-bucket",
+            "192.168.5.4",
+            "xn--bucket",
+            "bucket-s3alias",
+        ] {
+            assert!(S3BucketName::check(name).is_err(), "{name}");
+        }
+        assert!(S3BucketName::check("1.2.3.4.5").is_ok());
+    }
+
+    #[test]
+    fn test_validate() {
+        let validate = |s: &str| S3BucketName.validate(&s.parse().unwrap());
+        assert!(validate("arn:aws:s3:::my-bucket/Some_Key").is_ok());
+        assert!(validate("arn:aws:s3:::my-bucket-*").is_ok());
+        assert!(validate("arn:aws:s3:us-east-1:123456789012:accesspoint/Ap_1").is_ok());
+        assert!(validate("arn:aws:sqs:::My_Queue").is_ok());
+        assert_eq!(
+            validate("arn:aws:s3:::My_Bucket/key")
+                .unwrap_err()
+                .to_string(),
+            "Invalid resource: bucket name 'My_Bucket' contains 'M'"
+        );
+    }
+}